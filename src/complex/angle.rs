@@ -1,13 +1,15 @@
 //! Angle operations and conversions
 
+use std::cmp::Ordering;
 use std::f64::consts::PI;
 
-/// Represents an angle measurement that can be expressed in either degrees or radians.
+/// Represents an angle measurement that can be expressed in degrees, radians,
+/// gradians or turns.
 /// 
 /// The `Angle` type provides a safe and convenient way to work with angles, ensuring
-/// that conversions between degrees and radians are handled correctly. It maintains
-/// the internal representation in either degrees or radians as originally specified,
-/// performing conversions only when needed.
+/// that conversions between units are handled correctly. It maintains the internal
+/// representation in the unit originally specified, performing conversions only
+/// when needed.
 /// 
 /// # Examples
 /// 
@@ -23,7 +25,7 @@ use std::f64::consts::PI;
 /// // Both angles represent the same value
 /// assert!((deg_angle.to_radians() - rad_angle.to_radians()).abs() < 1e-10);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy)]
 pub enum Angle {
     /// Represents an angle measured in degrees (0-360).
     /// 
@@ -36,9 +38,31 @@ pub enum Angle {
     /// While any float value is accepted, you can normalize the angle to the
     /// equivalent of [0, 360) degrees using the `normalize()` method.
     Radian(f64),
+
+    /// Represents an angle measured in gradians (0-400).
+    /// 
+    /// A full circle is 400 gradians, so a right angle is exactly 100 gradians.
+    Gradian(f64),
+
+    /// Represents an angle measured in turns (0-1).
+    /// 
+    /// A full circle is a single turn, so a right angle is 0.25 turns.
+    Turn(f64),
 }
 
 impl Angle {
+    /// The zero angle (0°).
+    pub const ZERO: Angle = Angle::Degree(0.0);
+
+    /// A right angle (90°).
+    pub const RIGHT: Angle = Angle::Degree(90.0);
+
+    /// A straight angle (180°).
+    pub const STRAIGHT: Angle = Angle::Degree(180.0);
+
+    /// A full rotation (360°).
+    pub const FULL: Angle = Angle::Degree(360.0);
+
     /// Creates a new angle from a value in degrees.
     /// 
     /// This method stores the angle internally as degrees. The value is stored
@@ -84,6 +108,40 @@ impl Angle {
         Angle::Radian(radians)
     }
 
+    /// Creates a new angle from a value in gradians.
+    /// 
+    /// This method stores the angle internally as gradians, where a full
+    /// circle is 400 gradians. The value is stored as-is without normalization.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::Angle;
+    /// 
+    /// let angle = Angle::from_gradians(100.0);
+    /// assert!((angle.to_degrees() - 90.0).abs() < 1e-10);
+    /// ```
+    pub fn from_gradians(gradians: f64) -> Self {
+        Angle::Gradian(gradians)
+    }
+
+    /// Creates a new angle from a value in turns.
+    /// 
+    /// This method stores the angle internally as turns, where a full
+    /// circle is 1.0 turn. The value is stored as-is without normalization.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::Angle;
+    /// 
+    /// let angle = Angle::from_turns(0.25);
+    /// assert!((angle.to_degrees() - 90.0).abs() < 1e-10);
+    /// ```
+    pub fn from_turns(turns: f64) -> Self {
+        Angle::Turn(turns)
+    }
+
     /// Converts the angle to degrees, regardless of its internal representation.
     /// 
     /// This method performs the conversion from radians to degrees if necessary.
@@ -105,6 +163,8 @@ impl Angle {
         match self {
             Angle::Degree(deg) => *deg,
             Angle::Radian(rad) => rad * 180.0 / PI,
+            Angle::Gradian(grad) => grad * 0.9,
+            Angle::Turn(turns) => turns * 360.0,
         }
     }
 
@@ -129,6 +189,54 @@ impl Angle {
         match self {
             Angle::Degree(deg) => deg * PI / 180.0,
             Angle::Radian(rad) => *rad,
+            Angle::Gradian(grad) => grad * PI / 200.0,
+            Angle::Turn(turns) => turns * 2.0 * PI,
+        }
+    }
+
+    /// Converts the angle to gradians, regardless of its internal representation.
+    /// 
+    /// The conversion is performed via radians if necessary. The returned value
+    /// is not normalized and may be outside the range [0, 400).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::Angle;
+    /// 
+    /// let grad = Angle::from_gradians(50.0);
+    /// assert_eq!(grad.to_gradians(), 50.0);
+    /// 
+    /// let deg = Angle::from_degrees(90.0);
+    /// assert!((deg.to_gradians() - 100.0).abs() < 1e-10);
+    /// ```
+    pub fn to_gradians(&self) -> f64 {
+        match self {
+            Angle::Gradian(grad) => *grad,
+            _ => self.to_radians() * 200.0 / PI,
+        }
+    }
+
+    /// Converts the angle to turns, regardless of its internal representation.
+    /// 
+    /// The conversion is performed via radians if necessary. The returned value
+    /// is not normalized and may be outside the range [0, 1).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::Angle;
+    /// 
+    /// let turns = Angle::from_turns(0.5);
+    /// assert_eq!(turns.to_turns(), 0.5);
+    /// 
+    /// let deg = Angle::from_degrees(90.0);
+    /// assert!((deg.to_turns() - 0.25).abs() < 1e-10);
+    /// ```
+    pub fn to_turns(&self) -> f64 {
+        match self {
+            Angle::Turn(turns) => *turns,
+            _ => self.to_radians() / (2.0 * PI),
         }
     }
 
@@ -212,6 +320,64 @@ impl Angle {
         let result = if normalized < 0.0 { normalized + 360.0 } else { normalized };
         Angle::Degree(result)
     }
+
+    /// Checks whether two angles describe the same direction within a tolerance.
+    /// 
+    /// Both angles are compared by their radian values modulo a full rotation,
+    /// so `0°` and `360°` are considered equal, as are angles stored in
+    /// different units.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::Angle;
+    /// use std::f64::consts::PI;
+    /// 
+    /// let a = Angle::from_degrees(360.0);
+    /// let b = Angle::from_radians(0.0);
+    /// assert!(a.eq_approx(&b, 1e-10));
+    /// 
+    /// let c = Angle::from_degrees(90.0);
+    /// assert!(!c.eq_approx(&Angle::from_radians(PI), 1e-10));
+    /// ```
+    pub fn eq_approx(&self, other: &Angle, tol: f64) -> bool {
+        let full = 2.0 * PI;
+        let diff = (self.to_radians() - other.to_radians()).rem_euclid(full);
+        diff.min(full - diff) <= tol
+    }
+}
+
+/// Compares angles by their value in radians, regardless of the unit they
+/// are stored in.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusticle::Angle;
+/// use std::f64::consts::PI;
+/// 
+/// assert_eq!(Angle::from_degrees(180.0), Angle::from_radians(PI));
+/// ```
+impl PartialEq for Angle {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_radians() == other.to_radians()
+    }
+}
+
+/// Orders angles by their value in radians, regardless of the unit they
+/// are stored in. Angles are not normalized before comparison.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusticle::Angle;
+/// 
+/// assert!(Angle::from_degrees(45.0) < Angle::from_turns(0.25));
+/// ```
+impl PartialOrd for Angle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_radians().partial_cmp(&other.to_radians())
+    }
 }
 
 /// Implements the conversion from f64 to Angle, interpreting the value as degrees.
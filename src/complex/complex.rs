@@ -1,12 +1,166 @@
 //! Complex numbers and their operations
 
 use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+use std::cmp::Ordering;
 use std::fmt;
 use std::str::FromStr;
 use super::angle::Angle;
 
+/// Floating-point types that can be used as the parts of a `Complex`
+/// 
+/// This trait is implemented for `f32` and `f64`, and provides the constants
+/// and elementary functions that complex arithmetic is built on. It is sealed,
+/// so it cannot be implemented outside this crate.
+pub trait Float:
+    sealed::Sealed
+    + Copy
+    + PartialEq
+    + PartialOrd
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// The additive identity
+    const ZERO: Self;
+    /// The multiplicative identity
+    const ONE: Self;
+
+    /// Converts from an `f64`, rounding if necessary
+    fn from_f64(value: f64) -> Self;
+    /// Converts to an `f64` without loss of precision
+    fn to_f64(self) -> f64;
+    /// Returns `true` if this value is NaN
+    fn is_nan(self) -> bool;
+    /// Returns `true` if this value is neither infinite nor NaN
+    fn is_finite(self) -> bool;
+    /// Returns the absolute value
+    fn abs(self) -> Self;
+    /// Returns the square root
+    fn sqrt(self) -> Self;
+    /// Returns `e` raised to this power
+    fn exp(self) -> Self;
+    /// Returns the natural logarithm
+    fn ln(self) -> Self;
+    /// Returns the sine (in radians)
+    fn sin(self) -> Self;
+    /// Returns the cosine (in radians)
+    fn cos(self) -> Self;
+    /// Returns the four-quadrant arctangent of `self` (y) and `other` (x)
+    fn atan2(self, other: Self) -> Self;
+    /// Returns a number with the magnitude of `self` and the sign of `sign`
+    fn copysign(self, sign: Self) -> Self;
+    /// Returns the nearest integer, rounding half-way cases away from zero
+    fn round(self) -> Self;
+    /// Returns the largest integer less than or equal to `self`
+    fn floor(self) -> Self;
+    /// Returns the smallest integer greater than or equal to `self`
+    fn ceil(self) -> Self;
+    /// Computes `self * a + b` with a single rounding
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    /// Returns `sqrt(self² + other²)` without intermediate overflow or underflow
+    fn hypot(self, other: Self) -> Self;
+}
+
+mod sealed {
+    /// Restricts `Float` to the primitive floating-point types
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+macro_rules! impl_float {
+    ($t:ty) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn is_nan(self) -> bool {
+                <$t>::is_nan(self)
+            }
+
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            fn exp(self) -> Self {
+                <$t>::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                <$t>::ln(self)
+            }
+
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                <$t>::cos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                <$t>::atan2(self, other)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                <$t>::copysign(self, sign)
+            }
+
+            fn round(self) -> Self {
+                <$t>::round(self)
+            }
+
+            fn floor(self) -> Self {
+                <$t>::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                <$t>::ceil(self)
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                <$t>::mul_add(self, a, b)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                <$t>::hypot(self, other)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
 /// A complex number represented as a + bi
 /// 
+/// The parts are generic over a `Float` type. Most code uses the `Complex`
+/// alias for double precision; `Complex32` stores single-precision parts.
+/// 
 /// This type provides a comprehensive set of operations for working with complex numbers,
 /// including basic arithmetic, conversion between Cartesian and polar forms, and
 /// advanced mathematical operations.
@@ -38,35 +192,59 @@ use super::angle::Angle;
 /// let conjugate = z1.conjugate();
 /// let argument = z1.argument();
 /// ```
+/// 
+/// With the `serde` feature enabled, complex numbers serialize as
+/// `{"real": .., "imag": ..}`.
 #[derive(Clone, Copy, PartialEq)]
-pub struct Complex {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericComplex<T> {
     /// The real part of the complex number
-    pub real: f64,
+    pub real: T,
     /// The imaginary part of the complex number
-    pub imag: f64,
+    pub imag: T,
 }
 
-impl Default for Complex {
+/// A double-precision complex number
+/// 
+/// This is the complex type used throughout the crate.
+pub type Complex = GenericComplex<f64>;
+
+/// A single-precision complex number
+pub type Complex32 = GenericComplex<f32>;
+
+/// A double-precision complex number, identical to `Complex`
+pub type Complex64 = GenericComplex<f64>;
+
+impl<T: Float> Default for GenericComplex<T> {
     fn default() -> Self {
-        Complex::new(0.0, 0.0)
+        GenericComplex::new(T::ZERO, T::ZERO)
     }
 }
 
 /// Custom Debug implementation for Complex to print in the format a+ib
-impl fmt::Debug for Complex {
+impl<T: Float> fmt::Debug for GenericComplex<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.imag == 0.0 {
+        if self.imag == T::ZERO {
             // If imaginary part is zero, just print the real part
             write!(f, "{}", self.real)
         } else {
             // Format: a+ib or a-ib
-            let sign = if self.imag >= 0.0 { "+" } else { "" };
+            let sign = if self.imag < T::ZERO { "" } else { "+" };
             write!(f, "{}{}{}i", self.real, sign, self.imag)
         }
     }
 }
 
-impl Complex {
+impl<T: Float> GenericComplex<T> {
+    /// The additive identity `0`
+    pub const ZERO: Self = GenericComplex { real: T::ZERO, imag: T::ZERO };
+
+    /// The multiplicative identity `1`
+    pub const ONE: Self = GenericComplex { real: T::ONE, imag: T::ZERO };
+
+    /// The imaginary unit `i`, satisfying `i * i = -1`
+    pub const I: Self = GenericComplex { real: T::ZERO, imag: T::ONE };
+
     /// Creates a new complex number from its real and imaginary parts (Cartesian form)
     /// 
     /// # Examples
@@ -78,8 +256,8 @@ impl Complex {
     /// assert_eq!(z.real, 3.0);
     /// assert_eq!(z.imag, 4.0);
     /// ```
-    pub fn new(real: f64, imag: f64) -> Self {
-        Complex { real, imag }
+    pub fn new(real: T, imag: T) -> Self {
+        GenericComplex { real, imag }
     }
 
     /// Creates a complex number from its polar form (magnitude and angle)
@@ -94,9 +272,9 @@ impl Complex {
     /// assert!((z.real - 2.0 * (PI / 4.0).cos()).abs() < 1e-10);
     /// assert!((z.imag - 2.0 * (PI / 4.0).sin()).abs() < 1e-10);
     /// ```
-    pub fn from_polar(magnitude: f64, angle: Angle) -> Self {
-        let radians = angle.to_radians();
-        Complex {
+    pub fn from_polar(magnitude: T, angle: Angle) -> Self {
+        let radians = T::from_f64(angle.to_radians());
+        GenericComplex {
             real: magnitude * radians.cos(),
             imag: magnitude * radians.sin(),
         }
@@ -105,6 +283,8 @@ impl Complex {
     /// Returns the magnitude (absolute value) of the complex number
     /// 
     /// The magnitude is the distance from the origin to the point in the complex plane.
+    /// It is computed with `hypot`, so it stays finite for any finite parts, even
+    /// when squaring them would overflow.
     /// 
     /// # Examples
     /// 
@@ -114,8 +294,8 @@ impl Complex {
     /// let z = Complex::new(3.0, 4.0);
     /// assert_eq!(z.magnitude(), 5.0);
     /// ```
-    pub fn magnitude(&self) -> f64 {
-        (self.real * self.real + self.imag * self.imag).sqrt()
+    pub fn magnitude(&self) -> T {
+        self.real.hypot(self.imag)
     }
 
     /// Returns the norm of the complex number
@@ -131,8 +311,8 @@ impl Complex {
     /// let z = Complex::new(3.0, 4.0);
     /// assert_eq!(z.norm(), 5.0);
     /// ```
-    pub fn norm(&self) -> f64 {
-        (self.real * self.real + self.imag * self.imag).sqrt()
+    pub fn norm(&self) -> T {
+        self.magnitude()
     }
 
     /// Returns the argument (angle) of the complex number in radians
@@ -149,10 +329,70 @@ impl Complex {
     /// let z = Complex::new(0.0, 1.0);
     /// assert!((z.argument() - PI / 2.0).abs() < 1e-10);
     /// ```
-    pub fn argument(&self) -> f64 {
+    pub fn argument(&self) -> T {
         self.imag.atan2(self.real)
     }
 
+    /// Compares two complex numbers by magnitude
+    /// 
+    /// NaN magnitudes sort after all other values, so this can be passed
+    /// directly to `sort_by`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let mut values = vec![Complex::new(3.0, 4.0), Complex::new(0.0, -1.0), Complex::new(2.0, 0.0)];
+    /// values.sort_by(|a, b| a.cmp_by_magnitude(b));
+    /// assert_eq!(values[0], Complex::new(0.0, -1.0));
+    /// assert_eq!(values[2], Complex::new(3.0, 4.0));
+    /// ```
+    pub fn cmp_by_magnitude(&self, other: &Self) -> Ordering {
+        cmp_nan_last(self.magnitude(), other.magnitude())
+    }
+
+    /// Compares two complex numbers by principal argument in (-π, π]
+    /// 
+    /// NaN arguments sort after all other values, so this can be passed
+    /// directly to `sort_by`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let mut values = vec![Complex::new(0.0, 1.0), Complex::new(-1.0, -0.1), Complex::new(1.0, 0.0)];
+    /// values.sort_by(|a, b| a.cmp_by_argument(b));
+    /// assert_eq!(values[0], Complex::new(-1.0, -0.1));
+    /// assert_eq!(values[2], Complex::new(0.0, 1.0));
+    /// ```
+    pub fn cmp_by_argument(&self, other: &Self) -> Ordering {
+        cmp_nan_last(self.argument(), other.argument())
+    }
+
+    /// Returns the argument shifted by a multiple of 2π to lie closest to `reference`
+    /// 
+    /// Unlike `argument`, which always returns the principal value in (-π, π],
+    /// this picks the branch nearest to `reference`. Passing the previous phase
+    /// as the reference unwraps the phase continuously across a sweep.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// use std::f64::consts::PI;
+    /// 
+    /// let z = Complex::new(-1.0, -1e-9);
+    /// assert!((z.argument() + PI).abs() < 1e-6);
+    /// assert!((z.argument_near(PI) - PI).abs() < 1e-6);
+    /// ```
+    pub fn argument_near(&self, reference: T) -> T {
+        let tau = T::from_f64(std::f64::consts::TAU);
+        let argument = self.argument();
+        argument + tau * ((reference - argument) / tau).round()
+    }
+
     /// Returns the argument as an Angle
     /// 
     /// # Examples
@@ -164,7 +404,24 @@ impl Complex {
     /// assert_eq!(z.angle().to_degrees(), 90.0);
     /// ```
     pub fn angle(&self) -> Angle {
-        Angle::from_radians(self.argument())
+        Angle::from_radians(self.argument().to_f64())
+    }
+
+    /// Returns the polar form of the complex number as a (magnitude, angle) pair
+    /// 
+    /// This is the inverse of `from_polar`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let (magnitude, angle) = Complex::new(0.0, 2.0).to_polar();
+    /// assert_eq!(magnitude, 2.0);
+    /// assert_eq!(angle.to_degrees(), 90.0);
+    /// ```
+    pub fn to_polar(&self) -> (T, Angle) {
+        (self.magnitude(), self.angle())
     }
 
     /// Returns the complex conjugate of this number
@@ -182,7 +439,7 @@ impl Complex {
     /// assert_eq!(conjugate.imag, -4.0);
     /// ```
     pub fn conjugate(&self) -> Self {
-        Complex {
+        GenericComplex {
             real: self.real,
             imag: -self.imag,
         }
@@ -201,7 +458,7 @@ impl Complex {
     /// let z = Complex::new(3.0, 4.0);
     /// assert_eq!(z.magnitude_squared(), 25.0);
     /// ```
-    pub fn magnitude_squared(&self) -> f64 {
+    pub fn magnitude_squared(&self) -> T {
         self.real * self.real + self.imag * self.imag
     }
 
@@ -218,10 +475,33 @@ impl Complex {
     /// let z = Complex::new(3.0, 4.0);
     /// assert_eq!(z.norm_squared(), 25.0);
     /// ```
-    pub fn norm_squared(&self) -> f64 {
+    pub fn norm_squared(&self) -> T {
         self.real * self.real + self.imag * self.imag
     }
 
+    /// Computes `self * a + b` using fused multiply-add on the parts
+    /// 
+    /// Each part of the result is a sum of two products plus a part of `b`.
+    /// Both products are folded in with fused multiply-adds, so the product
+    /// terms are never rounded on their own. This mirrors `f64::mul_add` and is
+    /// typically more accurate than `self * a + b`, especially when terms cancel.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::new(1.0, 2.0);
+    /// let result = z.mul_add(Complex::new(3.0, -1.0), Complex::new(0.5, 0.5));
+    /// assert_eq!(result, Complex::new(5.5, 5.5));
+    /// ```
+    pub fn mul_add(&self, a: Self, b: Self) -> Self {
+        GenericComplex::new(
+            self.real.mul_add(a.real, (-self.imag).mul_add(a.imag, b.real)),
+            self.real.mul_add(a.imag, self.imag.mul_add(a.real, b.imag)),
+        )
+    }
+
     /// Creates a complex number from a string representation
     /// 
     /// # Examples
@@ -233,7 +513,12 @@ impl Complex {
     /// assert_eq!(z1.real, 2.0);
     /// assert_eq!(z1.imag, 3.0);
     /// ```
-    pub fn from_str(s: &str) -> Result<Self, String> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, String>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
         s.parse()
     }
 
@@ -256,64 +541,408 @@ impl Complex {
     /// ```
     pub fn exp(&self) -> Self {
         let exp_real = self.real.exp();
-        Complex::new(
+        GenericComplex::new(
             exp_real * self.imag.cos(),
             exp_real * self.imag.sin()
         )
     }
+
+    /// Computes the principal natural logarithm of a complex number
+    /// 
+    /// The result is `ln|z| + i*arg(z)`, with the imaginary part in (-π, π].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// use std::f64::consts::PI;
+    /// 
+    /// let z = Complex::new(-1.0, 0.0);
+    /// let log = z.ln();
+    /// assert_eq!(log.real, 0.0);
+    /// assert!((log.imag - PI).abs() < 1e-10);
+    /// ```
+    pub fn ln(&self) -> Self {
+        GenericComplex::new(self.magnitude().ln(), self.argument())
+    }
+
+    /// Computes the principal logarithm with respect to a real base
+    /// 
+    /// This is `ln(z) / ln(base)`. A base that is not positive, or a base of
+    /// exactly 1, has no well-defined logarithm; in that case both parts of the
+    /// result are NaN.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::new(8.0, 0.0);
+    /// assert!((z.log(2.0).real - 3.0).abs() < 1e-10);
+    /// 
+    /// let undefined = z.log(1.0);
+    /// assert!(undefined.real.is_nan() && undefined.imag.is_nan());
+    /// ```
+    pub fn log(&self, base: T) -> Self {
+        if base.is_nan() || base <= T::ZERO || base == T::ONE {
+            let nan = T::from_f64(f64::NAN);
+            return GenericComplex::new(nan, nan);
+        }
+        self.ln() / base.ln()
+    }
+
+    /// Computes the principal base-10 logarithm
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::from(100.0);
+    /// assert!((z.log10().real - 2.0).abs() < 1e-10);
+    /// ```
+    pub fn log10(&self) -> Self {
+        self.log(T::from_f64(10.0))
+    }
+
+    /// Computes the principal base-2 logarithm
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::new(0.0, 4.0);
+    /// assert!((z.log2().real - 2.0).abs() < 1e-10);
+    /// ```
+    pub fn log2(&self) -> Self {
+        self.log(T::ONE + T::ONE)
+    }
+
+    /// Computes the principal square root of a complex number
+    /// 
+    /// The result has a non-negative real part, and its imaginary part has the
+    /// same sign as the imaginary part of the input.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::new(-4.0, 0.0);
+    /// assert_eq!(z.sqrt(), Complex::new(0.0, 2.0));
+    /// 
+    /// let w = Complex::new(3.0, 4.0);
+    /// assert_eq!(w.sqrt(), Complex::new(2.0, 1.0));
+    /// ```
+    pub fn sqrt(&self) -> Self {
+        let r = self.magnitude();
+        let two = T::ONE + T::ONE;
+        GenericComplex::new(
+            ((r + self.real) / two).sqrt(),
+            ((r - self.real) / two).sqrt().copysign(self.imag)
+        )
+    }
+
+    /// Rounds both parts to the nearest integer
+    /// 
+    /// Half-way cases are rounded away from zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::new(2.0000001, -2.999999);
+    /// assert_eq!(z.round(), Complex::new(2.0, -3.0));
+    /// ```
+    pub fn round(&self) -> Self {
+        GenericComplex::new(self.real.round(), self.imag.round())
+    }
+
+    /// Rounds both parts down to the nearest integer
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::new(2.7, -2.2);
+    /// assert_eq!(z.floor(), Complex::new(2.0, -3.0));
+    /// ```
+    pub fn floor(&self) -> Self {
+        GenericComplex::new(self.real.floor(), self.imag.floor())
+    }
+
+    /// Rounds both parts up to the nearest integer
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::new(2.2, -2.7);
+    /// assert_eq!(z.ceil(), Complex::new(3.0, -2.0));
+    /// ```
+    pub fn ceil(&self) -> Self {
+        GenericComplex::new(self.real.ceil(), self.imag.ceil())
+    }
+
+    /// Returns the remainder of Gaussian-integer division by `divisor`
+    /// 
+    /// The quotient is `self / divisor` with both parts rounded to the nearest
+    /// integer, and the remainder is `self - quotient * divisor`. Rounding to
+    /// the nearest integer guarantees `|remainder|² <= |divisor|² / 2`, so the
+    /// remainder is always strictly smaller than the divisor.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `divisor` is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let a = Complex::new(7.0, 3.0);
+    /// let b = Complex::new(2.0, -1.0);
+    /// let r = a.gaussian_rem(b);
+    /// assert!(r.norm_squared() < b.norm_squared());
+    /// assert_eq!(r, r.round());
+    /// ```
+    pub fn gaussian_rem(&self, divisor: Self) -> Self {
+        assert!(divisor.real != T::ZERO || divisor.imag != T::ZERO, "Cannot take the Gaussian remainder by zero");
+
+        let quotient = (*self / divisor).round();
+        *self - quotient * divisor
+    }
+
+    /// Replaces any part whose absolute value is below `tol` with zero
+    /// 
+    /// This clears the tiny round-off residue left by numerical routines.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::new(1.0, 1e-16);
+    /// assert_eq!(z.chop(1e-12), Complex::new(1.0, 0.0));
+    /// ```
+    pub fn chop(&self, tol: T) -> Self {
+        let chop = |x: T| if x.abs() < tol { T::ZERO } else { x };
+        GenericComplex::new(chop(self.real), chop(self.imag))
+    }
+
+    /// Formats the number as `a+bX` with a custom imaginary symbol and precision
+    /// 
+    /// Both parts are always shown with exactly `precision` digits after the
+    /// decimal point, and `symbol` is written in place of `i` (for example `j`
+    /// in engineering notation).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Complex::new(1.5, -2.0);
+    /// assert_eq!(z.format_with('j', 1), "1.5-2.0j");
+    /// assert_eq!(z.format_with('i', 3), "1.500-2.000i");
+    /// ```
+    pub fn format_with(&self, symbol: char, precision: usize) -> String {
+        let sign = if self.imag < T::ZERO { '-' } else { '+' };
+        format!("{:.*}{}{:.*}{}", precision, self.real, sign, precision, self.imag.abs(), symbol)
+    }
+
+    /// Returns a bit-level key suitable for hashing
+    /// 
+    /// `Complex` does not implement `Hash` because `f64` does not. This key is
+    /// built from the raw bits of both parts, with `-0.0` normalized to `0.0`
+    /// so that numbers which compare equal produce equal keys.
+    /// 
+    /// NaN is the exception: NaN never compares equal to itself, yet two NaNs
+    /// with the same bit pattern produce the same key, while NaNs with
+    /// different payloads produce different keys. Avoid NaN values in keys if
+    /// this matters.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::Complex;
+    /// use std::collections::HashMap;
+    /// 
+    /// let mut cache = HashMap::new();
+    /// cache.insert(Complex::new(1.0, 0.0).to_bits_key(), "one");
+    /// assert_eq!(cache.get(&Complex::new(1.0, -0.0).to_bits_key()), Some(&"one"));
+    /// ```
+    pub fn to_bits_key(&self) -> (u64, u64) {
+        let normalize = |x: T| if x == T::ZERO { 0.0f64 } else { x.to_f64() };
+        (normalize(self.real).to_bits(), normalize(self.imag).to_bits())
+    }
+}
+
+/// Orders two floats, placing NaN after every other value
+fn cmp_nan_last<T: Float>(a: T, b: T) -> Ordering {
+    match (a.is_nan(), b.is_nan()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// Computes a greatest common divisor of two Gaussian integers
+/// 
+/// This runs the Euclidean algorithm with `gaussian_rem`, stopping once the
+/// remainder rounds to zero. The gcd is only unique up to multiplication by a
+/// unit (`1`, `-1`, `i` or `-i`), so any associate may be returned. The gcd of
+/// a number and zero is the number itself.
+/// 
+/// # Panics
+/// 
+/// Panics if either input has an infinite or NaN component, or a squared norm
+/// that overflows, since the Euclidean algorithm would never terminate.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusticle::complex::{gaussian_gcd, Complex};
+/// 
+/// // 5 = (2+i)(2-i), so the gcd is an associate of 2+i
+/// let g = gaussian_gcd(Complex::new(5.0, 0.0), Complex::new(2.0, 1.0));
+/// assert_eq!(g.norm_squared(), 5.0);
+/// assert_eq!(Complex::new(2.0, 1.0).gaussian_rem(g), Complex::new(0.0, 0.0));
+/// ```
+pub fn gaussian_gcd<T: Float>(a: GenericComplex<T>, b: GenericComplex<T>) -> GenericComplex<T> {
+    // Gaussian integers have integer norms, so anything below 1/2 is zero
+    let half = T::from_f64(0.5);
+    let is_zero = |z: GenericComplex<T>| z.norm_squared() < half;
+
+    let (mut a, mut b) = (a.round(), b.round());
+    assert!(
+        a.norm_squared().is_finite() && b.norm_squared().is_finite(),
+        "Gaussian gcd requires finite inputs"
+    );
+    while !is_zero(b) {
+        let remainder = a.gaussian_rem(b);
+        a = b;
+        b = remainder;
+    }
+    a
+}
+
+/// Returns both roots of `az² + bz + c = 0`
+/// 
+/// The roots are computed with the numerically stable form of the quadratic
+/// formula, `q = -(b ± √(b² - 4ac)) / 2` with the sign chosen to avoid
+/// cancellation, returning `(q / a, c / q)`. A repeated root is returned twice.
+/// 
+/// If `a` is zero the equation is linear and its single root `-c / b` is
+/// returned twice; if `a` and `b` are both zero there is no root and both
+/// results are non-finite.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusticle::complex::{solve_quadratic, Complex};
+/// 
+/// // z² + 1 = 0 has roots ±i
+/// let (z1, z2) = solve_quadratic(
+///     Complex::new(1.0, 0.0),
+///     Complex::new(0.0, 0.0),
+///     Complex::new(1.0, 0.0),
+/// );
+/// assert!((z1 * z2 - Complex::new(1.0, 0.0)).magnitude() < 1e-12);
+/// assert!((z1 + z2).magnitude() < 1e-12);
+/// ```
+pub fn solve_quadratic<T: Float>(
+    a: GenericComplex<T>,
+    b: GenericComplex<T>,
+    c: GenericComplex<T>,
+) -> (GenericComplex<T>, GenericComplex<T>) {
+    let zero = GenericComplex::new(T::ZERO, T::ZERO);
+    if a == zero {
+        let root = -c / b;
+        return (root, root);
+    }
+
+    let discriminant = (b * b - a * c * T::from_f64(4.0)).sqrt();
+    // Align the square root with b so that b + s does not cancel
+    let s = if (b.conjugate() * discriminant).real < T::ZERO { -discriminant } else { discriminant };
+    let q = -(b + s) / T::from_f64(2.0);
+    if q == zero {
+        // Only possible when b and c are both zero
+        return (zero, zero);
+    }
+    (q / a, c / q)
 }
 
 // Implement standard arithmetic operations
-impl Add for Complex {
-    type Output = Complex;
+impl<T: Float> Add for GenericComplex<T> {
+    type Output = GenericComplex<T>;
 
-    fn add(self, other: Complex) -> Complex {
-        Complex {
+    fn add(self, other: GenericComplex<T>) -> GenericComplex<T> {
+        GenericComplex {
             real: self.real + other.real,
             imag: self.imag + other.imag,
         }
     }
 }
 
-impl Sub for Complex {
-    type Output = Complex;
+impl<T: Float> Sub for GenericComplex<T> {
+    type Output = GenericComplex<T>;
 
-    fn sub(self, other: Complex) -> Complex {
-        Complex {
+    fn sub(self, other: GenericComplex<T>) -> GenericComplex<T> {
+        GenericComplex {
             real: self.real - other.real,
             imag: self.imag - other.imag,
         }
     }
 }
 
-impl Mul for Complex {
-    type Output = Complex;
+impl<T: Float> Mul for GenericComplex<T> {
+    type Output = GenericComplex<T>;
 
-    fn mul(self, other: Complex) -> Complex {
-        Complex {
+    fn mul(self, other: GenericComplex<T>) -> GenericComplex<T> {
+        GenericComplex {
             real: self.real * other.real - self.imag * other.imag,
             imag: self.real * other.imag + self.imag * other.real,
         }
     }
 }
 
-impl Div for Complex {
-    type Output = Complex;
+impl<T: Float> Div for GenericComplex<T> {
+    type Output = GenericComplex<T>;
 
-    fn div(self, other: Complex) -> Complex {
-        let denominator = other.magnitude_squared();
-        Complex {
-            real: (self.real * other.real + self.imag * other.imag) / denominator,
-            imag: (self.imag * other.real - self.real * other.imag) / denominator,
+    /// Divides using Smith's algorithm
+    /// 
+    /// Rather than dividing by `|other|²`, which overflows once the parts of
+    /// `other` exceed roughly `1e154`, the divisor is scaled by the ratio of its
+    /// smaller part to its larger part, keeping every intermediate in range.
+    fn div(self, other: GenericComplex<T>) -> GenericComplex<T> {
+        if other.real.abs() >= other.imag.abs() {
+            let ratio = other.imag / other.real;
+            let denominator = other.real + other.imag * ratio;
+            GenericComplex {
+                real: (self.real + self.imag * ratio) / denominator,
+                imag: (self.imag - self.real * ratio) / denominator,
+            }
+        } else {
+            let ratio = other.real / other.imag;
+            let denominator = other.real * ratio + other.imag;
+            GenericComplex {
+                real: (self.real * ratio + self.imag) / denominator,
+                imag: (self.imag * ratio - self.real) / denominator,
+            }
         }
     }
 }
 
-impl Neg for Complex {
-    type Output = Complex;
+impl<T: Float> Neg for GenericComplex<T> {
+    type Output = GenericComplex<T>;
 
-    fn neg(self) -> Complex {
-        Complex {
+    fn neg(self) -> GenericComplex<T> {
+        GenericComplex {
             real: -self.real,
             imag: -self.imag,
         }
@@ -321,22 +950,22 @@ impl Neg for Complex {
 }
 
 // Implement scalar operations
-impl Mul<f64> for Complex {
-    type Output = Complex;
+impl<T: Float> Mul<T> for GenericComplex<T> {
+    type Output = GenericComplex<T>;
 
-    fn mul(self, scalar: f64) -> Complex {
-        Complex {
+    fn mul(self, scalar: T) -> GenericComplex<T> {
+        GenericComplex {
             real: self.real * scalar,
             imag: self.imag * scalar,
         }
     }
 }
 
-impl Div<f64> for Complex {
-    type Output = Complex;
+impl<T: Float> Div<T> for GenericComplex<T> {
+    type Output = GenericComplex<T>;
 
-    fn div(self, scalar: f64) -> Complex {
-        Complex {
+    fn div(self, scalar: T) -> GenericComplex<T> {
+        GenericComplex {
             real: self.real / scalar,
             imag: self.imag / scalar,
         }
@@ -344,17 +973,45 @@ impl Div<f64> for Complex {
 }
 
 // Implement From trait for easy conversion
-impl From<f64> for Complex {
-    fn from(real: f64) -> Self {
-        Complex::new(real, 0.0)
+impl<T: Float> From<T> for GenericComplex<T> {
+    fn from(real: T) -> Self {
+        GenericComplex::new(real, T::ZERO)
+    }
+}
+
+// Conversions to and from num-complex
+#[cfg(feature = "num-complex")]
+impl<T: Float> From<num_complex::Complex<T>> for GenericComplex<T> {
+    fn from(z: num_complex::Complex<T>) -> Self {
+        GenericComplex::new(z.re, z.im)
     }
 }
 
-impl FromStr for Complex {
+#[cfg(feature = "num-complex")]
+impl<T: Float> From<GenericComplex<T>> for num_complex::Complex<T> {
+    fn from(z: GenericComplex<T>) -> Self {
+        num_complex::Complex::new(z.real, z.imag)
+    }
+}
+
+impl<T: Float + FromStr> FromStr for GenericComplex<T>
+where
+    T::Err: fmt::Display,
+{
     type Err = String;
 
     /// Parses a string into a Complex number
     /// 
+    /// The canonical formats are exactly the ones produced by `Debug`:
+    /// `a` when the imaginary part is zero, and `a+bi` or `a-bi` otherwise,
+    /// where `a` and `b` are written as by the float's `Display` impl. Every
+    /// finite value therefore round-trips through `format!("{:?}", z)`.
+    /// 
+    /// As shorthand, a bare imaginary part (`bi`, `i`, `-i`), a unit
+    /// coefficient (`a+i`, `a-i`), a leading `+` and exponent notation
+    /// (`1e5-2.5e-3i`) are also accepted. Either part may be one of the
+    /// special tokens `inf`, `-inf` or `nan`, as in `"inf+nan i"`.
+    /// 
     /// # Examples
     /// 
     /// ```
@@ -372,25 +1029,6 @@ impl FromStr for Complex {
             return Err("Empty string".to_string());
         }
 
-        // Handle pure real number
-        if !s.contains('i') {
-            let real = s.parse::<f64>().map_err(|e| format!("Invalid real part: {}", e))?;
-            return Ok(Complex::new(real, 0.0));
-        }
-
-        // Handle pure imaginary number
-        if !s.contains('+') && !s.contains('-') {
-            let imag_str = s.trim_end_matches('i');
-            let imag = if imag_str.is_empty() {
-                1.0
-            } else if imag_str == "-" {
-                -1.0
-            } else {
-                imag_str.parse::<f64>().map_err(|e| format!("Invalid imaginary part: {}", e))?
-            };
-            return Ok(Complex::new(0.0, imag));
-        }
-
         // Split into parts
         let mut parts = Vec::new();
         let mut current = String::new();
@@ -413,33 +1051,36 @@ impl FromStr for Complex {
         }
 
         // Parse parts
-        let mut real = 0.0;
-        let mut imag = 0.0;
+        let mut real = T::ZERO;
+        let mut imag = T::ZERO;
 
         for part in parts {
-            if part.contains('i') {
-                let imag_str = part.trim_end_matches('i');
+            // Only a trailing `i` marks an imaginary term, so `inf` and
+            // `-inf` are still read as real parts
+            let part = part.trim();
+            if let Some(imag_str) = part.strip_suffix('i') {
+                let imag_str = imag_str.trim_end();
                 let value = if imag_str.is_empty() {
-                    1.0
+                    T::ONE
                 } else if imag_str == "-" {
-                    -1.0
+                    -T::ONE
                 } else if imag_str == "+" {
-                    1.0
+                    T::ONE
                 } else {
-                    imag_str.parse::<f64>().map_err(|e| format!("Invalid imaginary part: {}", e))?
+                    imag_str.parse::<T>().map_err(|e| format!("Invalid imaginary part: {}", e))?
                 };
                 imag = value;
             } else {
-                let value = part.parse::<f64>().map_err(|e| format!("Invalid real part: {}", e))?;
+                let value = part.parse::<T>().map_err(|e| format!("Invalid real part: {}", e))?;
                 real = value;
             }
         }
 
-        Ok(Complex::new(real, imag))
+        Ok(GenericComplex::new(real, imag))
     }
 }
 
-impl AddAssign for Complex {
+impl<T: Float> AddAssign for GenericComplex<T> {
     /// Performs the `+=` operation.
     fn add_assign(&mut self, other: Self) {
         self.real += other.real;
@@ -447,7 +1088,7 @@ impl AddAssign for Complex {
     }
 }
 
-impl SubAssign for Complex {
+impl<T: Float> SubAssign for GenericComplex<T> {
     /// Performs the `-=` operation.
     fn sub_assign(&mut self, other: Self) {
         self.real -= other.real;
@@ -455,7 +1096,7 @@ impl SubAssign for Complex {
     }
 }
 
-impl MulAssign for Complex {
+impl<T: Float> MulAssign for GenericComplex<T> {
     /// Performs the `*=` operation.
     fn mul_assign(&mut self, other: Self) {
         let real = self.real * other.real - self.imag * other.imag;
@@ -465,28 +1106,24 @@ impl MulAssign for Complex {
     }
 }
 
-impl DivAssign for Complex {
+impl<T: Float> DivAssign for GenericComplex<T> {
     /// Performs the `/=` operation.
     fn div_assign(&mut self, other: Self) {
-        let denominator = other.magnitude_squared();
-        let real = (self.real * other.real + self.imag * other.imag) / denominator;
-        let imag = (self.imag * other.real - self.real * other.imag) / denominator;
-        self.real = real;
-        self.imag = imag;
+        *self = *self / other;
     }
 }
 
-impl MulAssign<f64> for Complex {
+impl<T: Float> MulAssign<T> for GenericComplex<T> {
     /// Performs the `*=` operation with a scalar.
-    fn mul_assign(&mut self, scalar: f64) {
+    fn mul_assign(&mut self, scalar: T) {
         self.real *= scalar;
         self.imag *= scalar;
     }
 }
 
-impl DivAssign<f64> for Complex {
+impl<T: Float> DivAssign<T> for GenericComplex<T> {
     /// Performs the `/=` operation with a scalar.
-    fn div_assign(&mut self, scalar: f64) {
+    fn div_assign(&mut self, scalar: T) {
         self.real /= scalar;
         self.imag /= scalar;
     }
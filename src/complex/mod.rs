@@ -5,10 +5,13 @@
 //! polar form conversions.
 
 pub mod angle;
+#[allow(clippy::module_inception)]
 pub mod complex;
+pub mod polynomial;
 pub mod vector;
 
 // Re-exports
 pub use angle::Angle;
-pub use complex::Complex;
+pub use complex::{gaussian_gcd, solve_quadratic, Complex, Complex32, Complex64, Float, GenericComplex};
+pub use polynomial::{eval_polynomial, polynomial_roots};
 pub use vector::ComplexVector;
\ No newline at end of file
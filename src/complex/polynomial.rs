@@ -0,0 +1,70 @@
+//! Polynomial evaluation and root finding
+
+use super::complex::Complex;
+use crate::linalg::matrix::Matrix;
+
+/// Evaluates a complex polynomial at `z` using Horner's method
+/// 
+/// The coefficients are given in ascending order of degree, so
+/// `[c0, c1, ..., cn]` represents `c0 + c1*z + ... + cn*z^n`. Each Horner step
+/// `acc * z + c` is computed with `Complex::mul_add`. The empty polynomial
+/// evaluates to zero.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusticle::complex::{eval_polynomial, Complex};
+/// 
+/// // 1 + z^2 vanishes at z = i
+/// let coeffs = [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+/// assert_eq!(eval_polynomial(&coeffs, Complex::new(0.0, 1.0)), Complex::new(0.0, 0.0));
+/// ```
+pub fn eval_polynomial(coeffs: &[Complex], z: Complex) -> Complex {
+    coeffs
+        .iter()
+        .rev()
+        .fold(Complex::new(0.0, 0.0), |acc, &c| acc.mul_add(z, c))
+}
+
+/// Finds all complex roots of a real polynomial
+/// 
+/// The coefficients are given in ascending order of degree, so
+/// `[c0, c1, ..., cn]` represents `c0 + c1*x + ... + cn*x^n`. Trailing zero
+/// coefficients are ignored. The roots are the eigenvalues of the companion
+/// matrix of the monic polynomial, found with the shifted QR algorithm, and are
+/// returned with multiplicity in no particular order. A nonzero constant
+/// polynomial has no roots.
+/// 
+/// # Panics
+/// 
+/// Panics if every coefficient is zero (or `coeffs` is empty)
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusticle::complex::polynomial_roots;
+/// 
+/// // x^2 + 1 has roots ±i
+/// let roots = polynomial_roots(&[1.0, 0.0, 1.0]);
+/// assert_eq!(roots.len(), 2);
+/// assert!(roots.iter().all(|r| r.real.abs() < 1e-6 && (r.imag.abs() - 1.0).abs() < 1e-6));
+/// ```
+pub fn polynomial_roots(coeffs: &[f64]) -> Vec<Complex> {
+    let Some(degree) = coeffs.iter().rposition(|&c| c != 0.0) else {
+        panic!("Polynomial must have at least one nonzero coefficient");
+    };
+    if degree == 0 {
+        return vec![];
+    }
+
+    // Companion matrix: ones on the subdiagonal and -c_i/c_n in the last column
+    let leading = coeffs[degree];
+    let mut companion = Matrix::zeros(degree, degree);
+    for (i, &c) in coeffs[..degree].iter().enumerate() {
+        if i > 0 {
+            companion.set(i, i - 1, 1.0);
+        }
+        companion.set(i, degree - 1, -c / leading);
+    }
+    companion.eigenvalues(500 * degree, 1e-14)
+}
@@ -1,7 +1,10 @@
 //! Complex vectors and their operations
 
-use std::ops::{Add, Sub, Mul, Neg};
+use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut, Range};
 use std::fmt;
+use std::str::FromStr;
+use std::f64::consts::PI;
+use super::angle::Angle;
 use super::complex::Complex;
 use crate::linalg::matrix::Matrix;
 
@@ -69,143 +72,1009 @@ impl ComplexVector {
         }
     }
     
+    /// Creates an empty vector with room for `capacity` components
+    /// 
+    /// Use together with `push` to build a vector without reallocating.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::ComplexVector;
+    /// 
+    /// let v = ComplexVector::with_capacity(4);
+    /// assert_eq!(v.dimension(), 0);
+    /// assert!(v.components.capacity() >= 4);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        ComplexVector {
+            components: Vec::with_capacity(capacity),
+        }
+    }
+    
     /// Returns the dimension of the vector
     /// 
-    /// # Examples
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+    /// assert_eq!(v.dimension(), 2);
+    /// ```
+    pub fn dimension(&self) -> usize {
+        self.components.len()
+    }
+    
+    /// Appends a component to the end of the vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let mut v = ComplexVector::with_capacity(2);
+    /// v.push(Complex::new(1.0, 2.0));
+    /// assert_eq!(v.dimension(), 1);
+    /// assert_eq!(v[0], Complex::new(1.0, 2.0));
+    /// ```
+    pub fn push(&mut self, c: Complex) {
+        self.components.push(c);
+    }
+    
+    /// Returns an iterator over the components of the vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 1.0)]);
+    /// let magnitudes: Vec<f64> = v.iter().map(|c| c.magnitude()).collect();
+    /// assert_eq!(magnitudes, vec![5.0, 1.0]);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, Complex> {
+        self.components.iter()
+    }
+
+    /// Returns an iterator that allows modifying each component of the vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let mut v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+    /// for c in v.iter_mut() {
+    ///     *c = c.conjugate();
+    /// }
+    /// assert_eq!(v.components[0], Complex::new(1.0, -2.0));
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Complex> {
+        self.components.iter_mut()
+    }
+    
+    /// Checks if the vector is a zero vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v1 = ComplexVector::zeros(2);
+    /// assert!(v1.is_zero());
+    /// 
+    /// let v2 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+    /// assert!(!v2.is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.components.iter().all(|c| c.real == 0.0 && c.imag == 0.0)
+    }
+    
+    /// Returns the sum of the components
+    /// 
+    /// The sum of an empty vector is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, -1.0)]);
+    /// assert_eq!(v.sum(), Complex::new(4.0, 1.0));
+    /// ```
+    pub fn sum(&self) -> Complex {
+        let mut result = Complex::new(0.0, 0.0);
+        for c in &self.components {
+            result += *c;
+        }
+        result
+    }
+    
+    /// Returns the arithmetic mean of the components
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the vector is empty
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, -1.0)]);
+    /// assert_eq!(v.mean(), Complex::new(2.0, 0.5));
+    /// ```
+    pub fn mean(&self) -> Complex {
+        assert!(self.dimension() > 0, "Cannot compute the mean of an empty vector");
+        self.sum() / self.dimension() as f64
+    }
+    
+    /// Returns the running partial sums of the components
+    /// 
+    /// Entry `k` of the result is the sum of components `0..=k`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(2.0, 1.0), Complex::new(3.0, -1.0)]);
+    /// let sums = v.cumsum();
+    /// assert_eq!(sums[1], Complex::new(3.0, 1.0));
+    /// assert_eq!(sums[2], Complex::new(6.0, 0.0));
+    /// ```
+    pub fn cumsum(&self) -> ComplexVector {
+        self.components.iter()
+            .scan(Complex::new(0.0, 0.0), |sum, &c| {
+                *sum += c;
+                Some(*sum)
+            })
+            .collect()
+    }
+    
+    /// Returns the differences between consecutive components
+    /// 
+    /// Entry `k` of the result is `self[k + 1] - self[k]`, so the result has one
+    /// component fewer than the vector (and is empty for an empty vector).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(4.0, 2.0), Complex::new(0.0, 2.0)]);
+    /// let differences = v.diff();
+    /// assert_eq!(differences.components, vec![Complex::new(3.0, 2.0), Complex::new(-4.0, 0.0)]);
+    /// ```
+    pub fn diff(&self) -> ComplexVector {
+        self.components.windows(2).map(|pair| pair[1] - pair[0]).collect()
+    }
+    
+    /// Returns the Euclidean norm (magnitude) of the vector
+    /// 
+    /// The Euclidean norm is the square root of the sum of the squares of the magnitudes
+    /// of each component. The magnitudes are divided by the largest one before
+    /// squaring and the result is rescaled afterwards, so the norm stays finite
+    /// whenever it is representable, even if squaring a component would overflow.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 5.0)]);
+    /// assert!((v.norm() - 7.07).abs() < 0.01); // sqrt(5^2 + 5^2) = sqrt(50) ≈ 7.07
+    /// ```
+    pub fn norm(&self) -> f64 {
+        // Unlike f64::max, this keeps a NaN magnitude once it has been seen
+        let scale = self.components.iter()
+            .map(|c| c.magnitude())
+            .fold(0.0, |max, m| if m > max || m.is_nan() { m } else { max });
+        if scale == 0.0 || !scale.is_finite() {
+            return scale;
+        }
+        let sum_squares = self.components.iter()
+            .map(|c| (c.magnitude() / scale).powi(2))
+            .sum::<f64>();
+        scale * sum_squares.sqrt()
+    }
+    
+    /// Returns the squared Euclidean norm of the vector
+    /// 
+    /// This is more efficient than computing the norm and then squaring it. The
+    /// squares are summed without rescaling, so the result overflows to infinity
+    /// once any component magnitude exceeds roughly `1e154`; use `norm` when the
+    /// components may be that large.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 5.0)]);
+    /// assert_eq!(v.norm_squared(), 50.0); // 5^2 + 5^2 = 50
+    /// ```
+    pub fn norm_squared(&self) -> f64 {
+        self.components.iter()
+            .map(|c| c.magnitude_squared())
+            .sum::<f64>()
+    }
+    
+    /// Returns the Euclidean distance between this vector and another vector
+    /// 
+    /// This is equivalent to `(self - other).norm()` without cloning either vector.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the vectors have different dimensions
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v1 = ComplexVector::new(vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)]);
+    /// let v2 = ComplexVector::new(vec![Complex::new(4.0, 5.0), Complex::new(2.0, 0.0)]);
+    /// assert_eq!(v1.distance(&v2), 5.0);
+    /// ```
+    pub fn distance(&self, other: &ComplexVector) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+
+    /// Returns the squared Euclidean distance between this vector and another vector
+    /// 
+    /// This avoids the square root, which makes it cheaper for comparing distances.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the vectors have different dimensions
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v1 = ComplexVector::new(vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)]);
+    /// let v2 = ComplexVector::new(vec![Complex::new(4.0, 5.0), Complex::new(2.0, 0.0)]);
+    /// assert_eq!(v1.distance_squared(&v2), 25.0);
+    /// ```
+    pub fn distance_squared(&self, other: &ComplexVector) -> f64 {
+        assert_eq!(self.dimension(), other.dimension(), "Vectors must have the same dimension for distance");
+
+        self.components.iter()
+            .zip(other.components.iter())
+            .map(|(a, b)| (*a - *b).magnitude_squared())
+            .sum::<f64>()
+    }
+    
+    /// Returns the magnitude of each component
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, -2.0)]);
+    /// assert_eq!(v.magnitudes(), vec![5.0, 2.0]);
+    /// ```
+    pub fn magnitudes(&self) -> Vec<f64> {
+        self.components.iter().map(|c| c.magnitude()).collect()
+    }
+    
+    /// Returns the angle (phase) of each component
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// use std::f64::consts::PI;
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+    /// let angles = v.arguments();
+    /// assert_eq!(angles[0].to_radians(), 0.0);
+    /// assert!((angles[1].to_radians() - PI / 2.0).abs() < 1e-10);
+    /// ```
+    pub fn arguments(&self) -> Vec<Angle> {
+        self.components.iter().map(|c| c.angle()).collect()
+    }
+    
+    /// Returns the inner product (dot product) of this vector with another vector
+    /// 
+    /// The inner product is the sum of the products of corresponding components,
+    /// where the second vector's components are conjugated. Use `dot` for the
+    /// plain bilinear product without conjugation.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v1 = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+    /// let v2 = ComplexVector::new(vec![Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)]);
+    /// 
+    /// let inner_prod = v1.inner_product(&v2);
+    /// // (1+2i)(5-6i) + (3+4i)(7-8i) = (17+4i) + (53+4i) = 70+8i
+    /// assert_eq!(inner_prod.real, 70.0);
+    /// assert_eq!(inner_prod.imag, 8.0);
+    /// ```
+    pub fn inner_product(&self, other: &ComplexVector) -> Complex {
+        assert_eq!(self.dimension(), other.dimension(), "Vectors must have the same dimension for inner product");
+        
+        let mut result = Complex::new(0.0, 0.0);
+        for i in 0..self.dimension() {
+            result += self.components[i] * other.components[i].conjugate();
+        }
+        result
+    }
+    
+    /// Returns the inner product of this vector with another under a diagonal metric
+    /// 
+    /// Computes `Σ w_i * a_i * conj(b_i)`, which reduces to `inner_product` when
+    /// every weight is one.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the vectors have different dimensions or if the number of
+    /// weights does not match the dimension
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v1 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+    /// let v2 = ComplexVector::new(vec![Complex::new(2.0, 0.0), Complex::new(0.0, 1.0)]);
+    /// 
+    /// // 3 * 1 * 2 + 0.5 * i * (-i) = 6.5
+    /// let weighted = v1.weighted_inner_product(&v2, &[3.0, 0.5]);
+    /// assert_eq!(weighted, Complex::new(6.5, 0.0));
+    /// ```
+    pub fn weighted_inner_product(&self, other: &ComplexVector, weights: &[f64]) -> Complex {
+        assert_eq!(self.dimension(), other.dimension(), "Vectors must have the same dimension for inner product");
+        assert_eq!(self.dimension(), weights.len(), "Weights must match the vector dimension");
+
+        self.components.iter()
+            .zip(other.components.iter())
+            .zip(weights)
+            .fold(Complex::new(0.0, 0.0), |acc, ((a, b), &w)| acc + *a * b.conjugate() * w)
+    }
+    
+    /// Returns the bilinear dot product of this vector with another vector
+    /// 
+    /// This is the sum `a_i * b_i` over corresponding components, with **no**
+    /// conjugation. It differs from `inner_product` whenever `other` has
+    /// nonzero imaginary parts: in particular `v.dot(&v)` is not the squared
+    /// norm of a complex vector, while `v.inner_product(&v)` is.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(0.0, 1.0)]);
+    /// 
+    /// // i * i = -1, whereas i * conj(i) = 1
+    /// assert_eq!(v.dot(&v), Complex::new(-1.0, 0.0));
+    /// assert_eq!(v.inner_product(&v), Complex::new(1.0, 0.0));
+    /// ```
+    pub fn dot(&self, other: &ComplexVector) -> Complex {
+        assert_eq!(self.dimension(), other.dimension(), "Vectors must have the same dimension for dot product");
+        
+        let mut result = Complex::new(0.0, 0.0);
+        for i in 0..self.dimension() {
+            result += self.components[i] * other.components[i];
+        }
+        result
+    }
+    
+    /// Returns the element-wise complex conjugate of this vector
+    /// 
+    /// Since `inner_product` conjugates its second argument, `a.inner_product(&b)`
+    /// equals the sum of the components of `a.hadamard(&b.conjugate())`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]);
+    /// let conjugate = v.conjugate();
+    /// assert_eq!(conjugate.components[0], Complex::new(1.0, -2.0));
+    /// assert_eq!(conjugate.components[1], Complex::new(3.0, 4.0));
+    /// ```
+    pub fn conjugate(&self) -> Self {
+        let mut result = self.clone();
+        result.conjugate_mut();
+        result
+    }
+
+    /// Conjugates every component of this vector in place
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let mut v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]);
+    /// v.conjugate_mut();
+    /// assert_eq!(v.components[0], Complex::new(1.0, -2.0));
+    /// assert_eq!(v.components[1], Complex::new(3.0, 4.0));
+    /// ```
+    pub fn conjugate_mut(&mut self) {
+        for c in self.components.iter_mut() {
+            *c = c.conjugate();
+        }
+    }
+
+    /// Returns the angle between this vector and another vector
+    /// 
+    /// The angle is `acos(|<a, b>| / (|a| |b|))`, which lies in `[0, π/2]` since
+    /// the magnitude of the inner product is used.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if either vector is a zero vector or the dimensions differ
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v1 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+    /// let v2 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)]);
+    /// 
+    /// let angle = v1.angle_between(&v2);
+    /// assert!((angle.to_degrees() - 45.0).abs() < 1e-10);
+    /// ```
+    pub fn angle_between(&self, other: &ComplexVector) -> Angle {
+        let norms = self.norm() * other.norm();
+        assert!(norms != 0.0, "Cannot compute the angle with a zero vector");
+
+        let cosine = self.inner_product(other).magnitude() / norms;
+        Angle::from_radians(cosine.min(1.0).acos())
+    }
+
+    /// Returns the Hadamard (element-wise) product of this vector with another vector
+    /// 
+    /// Unlike the inner product, no conjugation or summation is performed: each
+    /// component of the result is the product of the corresponding components.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the vectors have different dimensions
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v1 = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 0.0)]);
+    /// let v2 = ComplexVector::new(vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)]);
+    /// 
+    /// let product = v1.hadamard(&v2);
+    /// assert_eq!(product.components[0], Complex::new(-2.0, 1.0));
+    /// assert_eq!(product.components[1], Complex::new(6.0, 0.0));
+    /// ```
+    pub fn hadamard(&self, other: &ComplexVector) -> ComplexVector {
+        assert_eq!(self.dimension(), other.dimension(), "Vectors must have the same dimension for Hadamard product");
+
+        let mut result = Vec::with_capacity(self.dimension());
+        for i in 0..self.dimension() {
+            result.push(self.components[i] * other.components[i]);
+        }
+        ComplexVector::new(result)
+    }
+
+    /// Returns the cross product of this vector with another 3-dimensional vector
+    /// 
+    /// The components are combined using the standard determinant formula with
+    /// complex multiplication and no conjugation, so for real vectors (zero
+    /// imaginary parts) this is the familiar 3D cross product.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if either vector is not 3-dimensional
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let x = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0)]);
+    /// let y = ComplexVector::new(vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+    /// 
+    /// let z = x.cross(&y);
+    /// assert_eq!(z.components[2], Complex::new(1.0, 0.0));
+    /// ```
+    pub fn cross(&self, other: &ComplexVector) -> ComplexVector {
+        assert!(
+            self.dimension() == 3 && other.dimension() == 3,
+            "Cross product is only defined for 3-dimensional vectors"
+        );
+
+        let a = &self.components;
+        let b = &other.components;
+        ComplexVector::new(vec![
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ])
+    }
+    
+    /// Replaces tiny real and imaginary parts of each component with zero
+    /// 
+    /// Applies `Complex::chop` to every component.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1e-17, 2.0), Complex::new(3.0, -1e-15)]);
+    /// let chopped = v.chop(1e-12);
+    /// assert_eq!(chopped[0], Complex::new(0.0, 2.0));
+    /// assert_eq!(chopped[1], Complex::new(3.0, 0.0));
+    /// ```
+    pub fn chop(&self, tol: f64) -> Self {
+        self.iter().map(|c| c.chop(tol)).collect()
+    }
+    
+    /// Returns the normalized version of this vector (unit vector)
+    /// 
+    /// The normalized vector has the same direction but a magnitude of 1.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 5.0)]);
+    /// let normalized = v.normalize();
+    /// assert!((normalized.norm() - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn normalize(&self) -> Self {
+        let mut normalized = self.clone();
+        normalized.normalize_mut();
+        normalized
+    }
+
+    /// Returns the normalized version of this vector, or `None` for a zero vector
+    /// 
+    /// This is the non-panicking counterpart of `normalize`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(3.0, 4.0)]);
+    /// assert!(v.try_normalize().is_some());
+    /// assert!(ComplexVector::zeros(2).try_normalize().is_none());
+    /// ```
+    pub fn try_normalize(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm == 0.0 {
+            return None;
+        }
+        Some(ComplexVector::new(self.components.iter().map(|&c| c / norm).collect()))
+    }
+
+    /// Normalizes this vector in place
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the vector is a zero vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let mut v = ComplexVector::new(vec![Complex::new(0.0, 2.0)]);
+    /// v.normalize_mut();
+    /// assert_eq!(v[0], Complex::new(0.0, 1.0));
+    /// ```
+    pub fn normalize_mut(&mut self) {
+        let norm = self.norm();
+        assert!(norm != 0.0, "Cannot normalize a zero vector");
+        
+        for component in self.components.iter_mut() {
+            *component /= norm;
+        }
+    }
+
+    /// Returns the projection of this vector onto another vector
+    /// 
+    /// The projection is `(<self, other> / <other, other>) * other`, the component
+    /// of this vector along the direction of `other`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `other` is a zero vector or the dimensions differ
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(3.0, 1.0), Complex::new(4.0, 0.0)]);
+    /// let e0 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+    /// 
+    /// let projected = v.project_onto(&e0);
+    /// assert_eq!(projected.components[0], Complex::new(3.0, 1.0));
+    /// assert_eq!(projected.components[1], Complex::new(0.0, 0.0));
+    /// ```
+    pub fn project_onto(&self, other: &ComplexVector) -> Self {
+        let norm_squared = other.norm_squared();
+        assert!(norm_squared != 0.0, "Cannot project onto a zero vector");
+
+        let coefficient = self.inner_product(other) / norm_squared;
+        let mut projected = other.clone();
+        for i in 0..other.dimension() {
+            projected.components[i] = coefficient * projected.components[i];
+        }
+        projected
+    }
+
+    /// Returns the rejection of this vector from another vector
+    /// 
+    /// The rejection is `self - self.project_onto(other)`, the component of this
+    /// vector orthogonal to `other`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `other` is a zero vector or the dimensions differ
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(3.0, 1.0), Complex::new(4.0, 0.0)]);
+    /// let e0 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+    /// 
+    /// let rejected = v.reject_from(&e0);
+    /// assert_eq!(rejected.components[0], Complex::new(0.0, 0.0));
+    /// assert_eq!(rejected.components[1], Complex::new(4.0, 0.0));
+    /// ```
+    pub fn reject_from(&self, other: &ComplexVector) -> Self {
+        self.clone() - self.project_onto(other)
+    }
+
+    /// Builds an orthonormal set spanning the same subspace as the given vectors
+    /// 
+    /// This uses the modified Gram-Schmidt process, which removes the projection
+    /// onto each basis vector one at a time for better numerical stability.
+    /// Vectors whose remaining norm drops below `1e-10` times their original norm
+    /// are linearly dependent on earlier vectors and are skipped, so the result
+    /// may contain fewer vectors than the input.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the vectors do not all have the same dimension
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v1 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)]);
+    /// let v2 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+    /// let v3 = v1.clone() * 2.0; // Linearly dependent, will be skipped
+    /// 
+    /// let basis = ComplexVector::orthonormalize(&[v1, v2, v3]);
+    /// assert_eq!(basis.len(), 2);
+    /// assert!(basis[0].inner_product(&basis[1]).magnitude() < 1e-10);
+    /// ```
+    pub fn orthonormalize(vectors: &[ComplexVector]) -> Vec<ComplexVector> {
+        let mut basis: Vec<ComplexVector> = Vec::with_capacity(vectors.len());
+        for vector in vectors {
+            let original_norm = vector.norm();
+            let mut residual = vector.clone();
+            for q in &basis {
+                let coefficient = residual.inner_product(q);
+                for i in 0..residual.dimension() {
+                    residual.components[i] -= coefficient * q.components[i];
+                }
+            }
+
+            if residual.norm() > 1e-10 * original_norm {
+                basis.push(residual.normalize());
+            }
+        }
+        basis
+    }
+
+    /// Converts a vector to a column matrix
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// use rusticle::linalg::Matrix;
     /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+    /// let matrix = v.to_matrix();
+    /// assert_eq!(matrix.rows(), 2);
+    /// assert_eq!(matrix.cols(), 1);
+    /// assert_eq!(matrix.get(0, 0), &Complex::new(1.0, 2.0));
+    /// assert_eq!(matrix.get(1, 0), &Complex::new(3.0, 4.0));
     /// ```
+    pub fn to_matrix(&self) -> Matrix<Complex> {
+        Matrix::new(self.dimension(), 1, self.components.clone())
+    }
+
+    /// Returns the outer product of this vector with another vector
+    /// 
+    /// The result is a `self.dimension() x other.dimension()` matrix whose entry
+    /// `(i, j)` is `self[i] * conj(other[j])`. The outer product of a unit vector
+    /// with itself is the projector onto that vector.
+    /// 
+    /// # Example
+    /// ```rust
     /// use rusticle::complex::{Complex, ComplexVector};
     /// 
-    /// let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
-    /// assert_eq!(v.dimension(), 2);
+    /// let v1 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+    /// let v2 = ComplexVector::new(vec![Complex::new(0.0, 1.0)]);
+    /// 
+    /// let outer = v1.outer_product(&v2);
+    /// assert_eq!(outer.rows(), 2);
+    /// assert_eq!(outer.cols(), 1);
+    /// assert_eq!(outer.get(0, 0), &Complex::new(0.0, -1.0));
+    /// assert_eq!(outer.get(1, 0), &Complex::new(1.0, 0.0));
     /// ```
-    pub fn dimension(&self) -> usize {
-        self.components.len()
+    pub fn outer_product(&self, other: &ComplexVector) -> Matrix<Complex> {
+        let mut data = Vec::with_capacity(self.dimension() * other.dimension());
+        for a in &self.components {
+            for b in &other.components {
+                data.push(*a * b.conjugate());
+            }
+        }
+        Matrix::new(self.dimension(), other.dimension(), data)
     }
-    
-    /// Checks if the vector is a zero vector
+
+    /// Returns the tensor (Kronecker) product of this vector with another vector
+    /// 
+    /// The result has dimension `d1 * d2`, and component `i * d2 + j` is
+    /// `self[i] * other[j]`. No conjugation is applied. This is the vector
+    /// counterpart of `Matrix::kronecker`.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use rusticle::complex::{Complex, ComplexVector};
     /// 
-    /// let v1 = ComplexVector::zeros(2);
-    /// assert!(v1.is_zero());
+    /// let a = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+    /// let b = ComplexVector::new(vec![Complex::new(0.0, 1.0), Complex::new(3.0, 0.0)]);
+    /// let product = a.tensor(&b);
+    /// assert_eq!(product.dimension(), 4);
+    /// assert_eq!(product[2], Complex::new(0.0, 2.0));
+    /// ```
+    pub fn tensor(&self, other: &ComplexVector) -> ComplexVector {
+        let mut result = ComplexVector::with_capacity(self.dimension() * other.dimension());
+        for a in &self.components {
+            for b in &other.components {
+                result.push(*a * *b);
+            }
+        }
+        result
+    }
+
+    /// Returns a copy of the components in the given range
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the range is reversed or extends past the vector's dimension
+    /// 
+    /// # Examples
     /// 
-    /// let v2 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
-    /// assert!(!v2.is_zero());
     /// ```
-    pub fn is_zero(&self) -> bool {
-        self.components.iter().all(|c| c.real == 0.0 && c.imag == 0.0)
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v: ComplexVector = (0..5).map(|k| Complex::new(k as f64, 0.0)).collect();
+    /// let window = v.slice(1..3);
+    /// assert_eq!(window.components, vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> ComplexVector {
+        assert!(
+            range.start <= range.end && range.end <= self.dimension(),
+            "Slice range {:?} out of bounds for vector of dimension {}", range, self.dimension()
+        );
+
+        ComplexVector::new(self.components[range].to_vec())
     }
-    
-    /// Returns the Euclidean norm (magnitude) of the vector
+
+    /// Splits the vector into copies of the components before and from `mid`
     /// 
-    /// The Euclidean norm is the square root of the sum of the squares of the magnitudes
-    /// of each component.
+    /// # Panics
+    /// 
+    /// Panics if `mid` is greater than the vector's dimension
     /// 
     /// # Examples
     /// 
     /// ```
     /// use rusticle::complex::{Complex, ComplexVector};
     /// 
-    /// let v = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 5.0)]);
-    /// assert!((v.norm() - 7.07).abs() < 0.01); // sqrt(5^2 + 5^2) = sqrt(50) ≈ 7.07
+    /// let v: ComplexVector = (0..4).map(|k| Complex::new(k as f64, 0.0)).collect();
+    /// let (left, right) = v.split_at(1);
+    /// assert_eq!(left.dimension(), 1);
+    /// assert_eq!(right[0], Complex::new(1.0, 0.0));
     /// ```
-    pub fn norm(&self) -> f64 {
-        let sum_squares = self.components.iter()
-            .map(|c| c.magnitude_squared())
-            .sum::<f64>();
-        sum_squares.sqrt()
+    pub fn split_at(&self, mid: usize) -> (ComplexVector, ComplexVector) {
+        assert!(
+            mid <= self.dimension(),
+            "Split index {} out of bounds for vector of dimension {}", mid, self.dimension()
+        );
+
+        let (left, right) = self.components.split_at(mid);
+        (ComplexVector::new(left.to_vec()), ComplexVector::new(right.to_vec()))
     }
-    
-    /// Returns the squared Euclidean norm of the vector
+
+    /// Returns this vector followed by the components of another vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let a = ComplexVector::new(vec![Complex::new(1.0, 0.0)]);
+    /// let b = ComplexVector::new(vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)]);
+    /// let joined = a.concat(&b);
+    /// assert_eq!(joined.dimension(), 3);
+    /// assert_eq!(joined[1], Complex::new(0.0, 1.0));
+    /// ```
+    pub fn concat(&self, other: &ComplexVector) -> ComplexVector {
+        let mut result = ComplexVector::with_capacity(self.dimension() + other.dimension());
+        result.components.extend_from_slice(&self.components);
+        result.components.extend_from_slice(&other.components);
+        result
+    }
+
+    /// Joins several vectors end to end, in order
     /// 
-    /// This is more efficient than computing the norm and then squaring it.
+    /// Joining no parts yields an empty vector.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use rusticle::complex::{Complex, ComplexVector};
     /// 
-    /// let v = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 5.0)]);
-    /// assert_eq!(v.norm_squared(), 50.0); // 5^2 + 5^2 = 50
+    /// let parts = [
+    ///     ComplexVector::new(vec![Complex::new(1.0, 0.0)]),
+    ///     ComplexVector::new(vec![]),
+    ///     ComplexVector::new(vec![Complex::new(2.0, 0.0), Complex::new(3.0, 0.0)]),
+    /// ];
+    /// let joined = ComplexVector::concat_all(&parts);
+    /// assert_eq!(joined.dimension(), 3);
+    /// assert_eq!(joined[2], Complex::new(3.0, 0.0));
     /// ```
-    pub fn norm_squared(&self) -> f64 {
-        self.components.iter()
-            .map(|c| c.magnitude_squared())
-            .sum::<f64>()
+    pub fn concat_all(parts: &[ComplexVector]) -> ComplexVector {
+        let mut result = ComplexVector::with_capacity(parts.iter().map(|p| p.dimension()).sum());
+        for part in parts {
+            result.components.extend_from_slice(&part.components);
+        }
+        result
     }
-    
-    /// Returns the inner product (dot product) of this vector with another vector
+
+    /// Returns a copy truncated or zero-padded to `new_len` components
     /// 
-    /// The inner product is the sum of the products of corresponding components,
-    /// where the second vector's components are conjugated.
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 1.0), Complex::new(2.0, 2.0)]);
+    /// assert_eq!(v.resize(3)[2], Complex::new(0.0, 0.0));
+    /// assert_eq!(v.resize(1).components, vec![Complex::new(1.0, 1.0)]);
+    /// ```
+    pub fn resize(&self, new_len: usize) -> ComplexVector {
+        let mut result = self.clone();
+        result.components.resize(new_len, Complex::ZERO);
+        result
+    }
+
+    /// Returns a copy zero-padded to the next power-of-two length
+    /// 
+    /// Vectors whose dimension is already a power of two are returned unchanged,
+    /// and an empty vector is padded to a single zero. The result is always a
+    /// valid input for `fft`.
     /// 
     /// # Examples
     /// 
     /// ```
     /// use rusticle::complex::{Complex, ComplexVector};
     /// 
-    /// let v1 = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
-    /// let v2 = ComplexVector::new(vec![Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)]);
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 0.0); 5]);
+    /// assert_eq!(v.pad_to_pow2().dimension(), 8);
+    /// ```
+    pub fn pad_to_pow2(&self) -> ComplexVector {
+        self.resize(self.dimension().next_power_of_two())
+    }
+
+    /// Computes the discrete Fourier transform of the vector
+    /// 
+    /// Uses the iterative radix-2 Cooley-Tukey algorithm in `O(n log n)` time.
+    /// The transform is unnormalized, `X[k] = Σ x[j] e^(-2πi jk/n)`, so that
+    /// `ifft` is its exact inverse.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the dimension is not a power of two
+    /// 
+    /// # Examples
     /// 
-    /// let inner_prod = v1.inner_product(&v2);
-    /// // (1+2i)(5-6i) + (3+4i)(7-8i) = (17+4i) + (53+4i) = 70+8i
-    /// assert_eq!(inner_prod.real, 70.0);
-    /// assert_eq!(inner_prod.imag, 8.0);
     /// ```
-    pub fn inner_product(&self, other: &ComplexVector) -> Complex {
-        assert_eq!(self.dimension(), other.dimension(), "Vectors must have the same dimension for inner product");
-        
-        let mut result = Complex::new(0.0, 0.0);
-        for i in 0..self.dimension() {
-            result = result + self.components[i] * other.components[i].conjugate();
-        }
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 0.0); 4]);
+    /// let spectrum = v.fft();
+    /// assert_eq!(spectrum[0], Complex::new(4.0, 0.0));
+    /// assert!(spectrum[1].magnitude() < 1e-12);
+    /// ```
+    pub fn fft(&self) -> ComplexVector {
+        let mut result = self.clone();
+        fft_in_place(&mut result.components, false);
         result
     }
-    
-    /// Returns the normalized version of this vector (unit vector)
+
+    /// Computes the inverse discrete Fourier transform of the vector
     /// 
-    /// The normalized vector has the same direction but a magnitude of 1.
+    /// This is `x[j] = (1/n) Σ X[k] e^(2πi jk/n)`, so `v.fft().ifft()`
+    /// reconstructs `v` up to rounding.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the dimension is not a power of two
     /// 
     /// # Examples
     /// 
     /// ```
     /// use rusticle::complex::{Complex, ComplexVector};
     /// 
-    /// let v = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 5.0)]);
-    /// let normalized = v.normalize();
-    /// assert!((normalized.norm() - 1.0).abs() < 1e-10);
+    /// let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5)]);
+    /// let restored = v.fft().ifft();
+    /// assert!((restored - v).norm() < 1e-12);
     /// ```
-    pub fn normalize(&self) -> Self {
-        let norm = self.norm();
-        assert!(norm != 0.0, "Cannot normalize a zero vector");
-        
-        let mut normalized = self.clone();
-        for i in 0..self.dimension() {
-            normalized.components[i] = normalized.components[i] / norm;
+    pub fn ifft(&self) -> ComplexVector {
+        let mut result = self.clone();
+        fft_in_place(&mut result.components, true);
+        let n = result.dimension() as f64;
+        for component in result.components.iter_mut() {
+            *component /= n;
         }
-        normalized
+        result
     }
 
-    /// Converts a vector to a column matrix
+    /// Returns the linear convolution of this vector with another vector
     /// 
-    /// # Example
-    /// ```rust
+    /// The result has dimension `n + m - 1` and entries
+    /// `(a * b)[k] = Σ a[j] b[k - j]`, computed by direct summation in `O(nm)`
+    /// time, which is exact and fast for short filters. Convolving with an
+    /// empty vector yields an empty vector.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
     /// use rusticle::complex::{Complex, ComplexVector};
-    /// use rusticle::linalg::Matrix;
     /// 
-    /// let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
-    /// let matrix = v.to_matrix();
-    /// assert_eq!(matrix.rows(), 2);
-    /// assert_eq!(matrix.cols(), 1);
-    /// assert_eq!(matrix.get(0, 0), &Complex::new(1.0, 2.0));
-    /// assert_eq!(matrix.get(1, 0), &Complex::new(3.0, 4.0));
+    /// let a = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+    /// let b = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)]);
+    /// let c = a.convolve(&b);
+    /// assert_eq!(c.components, vec![
+    ///     Complex::new(1.0, 0.0), Complex::new(3.0, 0.0), Complex::new(2.0, 0.0)
+    /// ]);
     /// ```
-    pub fn to_matrix(&self) -> Matrix<Complex> {
-        Matrix::new(self.dimension(), 1, self.components.clone())
+    pub fn convolve(&self, other: &ComplexVector) -> ComplexVector {
+        if self.dimension() == 0 || other.dimension() == 0 {
+            return ComplexVector::new(vec![]);
+        }
+
+        let mut result = ComplexVector::zeros(self.dimension() + other.dimension() - 1);
+        for (i, a) in self.components.iter().enumerate() {
+            for (j, b) in other.components.iter().enumerate() {
+                result.components[i + j] += *a * *b;
+            }
+        }
+        result
     }
 
     /// Creates a vector from a column matrix
@@ -260,15 +1129,53 @@ impl ComplexVector {
         assert_eq!(self.dimension(), matrix.cols(), "Vector dimension must match matrix columns");
         
         let mut result = vec![Complex::new(0.0, 0.0); matrix.rows()];
-        for i in 0..matrix.rows() {
+        for (i, entry) in result.iter_mut().enumerate() {
             for j in 0..matrix.cols() {
-                result[i] = result[i] + self.components[j] * *matrix.get(i, j);
+                *entry += self.components[j] * *matrix.get(i, j);
             }
         }
         self.components = result;
     }
 }
 
+/// Runs an unnormalized radix-2 FFT, using `e^(+2πi/n)` twiddles when `inverse` is set
+fn fft_in_place(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    assert!(n.is_power_of_two(), "FFT length must be a power of two, got {}", n);
+    if n == 1 {
+        return;
+    }
+
+    // Reorder the input into bit-reversed index order
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    // Combine butterflies of doubling length, computing each twiddle directly
+    // rather than by repeated multiplication to avoid accumulating error
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let twiddles: Vec<Complex> = (0..half)
+            .map(|k| Complex::from_polar(1.0, Angle::from_radians(sign * 2.0 * PI * k as f64 / len as f64)))
+            .collect();
+        for start in (0..n).step_by(len) {
+            for k in 0..half {
+                let even = data[start + k];
+                let odd = data[start + k + half] * twiddles[k];
+                data[start + k] = even + odd;
+                data[start + k + half] = even - odd;
+            }
+        }
+        len *= 2;
+    }
+}
+
 /// Custom Debug implementation for ComplexVector
 impl fmt::Debug for ComplexVector {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -283,6 +1190,87 @@ impl fmt::Debug for ComplexVector {
     }
 }
 
+/// Implement read access to components via `v[i]`
+impl Index<usize> for ComplexVector {
+    type Output = Complex;
+
+    fn index(&self, index: usize) -> &Complex {
+        assert!(index < self.dimension(), "Index {} out of bounds for vector of dimension {}", index, self.dimension());
+        &self.components[index]
+    }
+}
+
+/// Implement write access to components via `v[i] = c`
+impl IndexMut<usize> for ComplexVector {
+    fn index_mut(&mut self, index: usize) -> &mut Complex {
+        assert!(index < self.dimension(), "Index {} out of bounds for vector of dimension {}", index, self.dimension());
+        &mut self.components[index]
+    }
+}
+
+/// Implement consuming iteration over the components
+impl IntoIterator for ComplexVector {
+    type Item = Complex;
+    type IntoIter = std::vec::IntoIter<Complex>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.into_iter()
+    }
+}
+
+/// Implement borrowing iteration over the components
+impl<'a> IntoIterator for &'a ComplexVector {
+    type Item = &'a Complex;
+    type IntoIter = std::slice::Iter<'a, Complex>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components.iter()
+    }
+}
+
+/// Implement collecting complex numbers into a vector
+impl FromIterator<Complex> for ComplexVector {
+    fn from_iter<I: IntoIterator<Item = Complex>>(iter: I) -> Self {
+        ComplexVector::new(iter.into_iter().collect())
+    }
+}
+
+impl FromStr for ComplexVector {
+    type Err = String;
+
+    /// Parses a bracketed, comma-separated list of complex numbers
+    /// 
+    /// Each element is parsed with the `Complex` parser, and whitespace around
+    /// elements is ignored. This accepts the `Debug` output of a vector.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let v: ComplexVector = "[1+2i, 3-4i, 5i]".parse().unwrap();
+    /// assert_eq!(v.dimension(), 3);
+    /// assert_eq!(v[2], Complex::new(0.0, 5.0));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s.trim()
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .ok_or_else(|| "Vector must be enclosed in square brackets".to_string())?;
+
+        if inner.trim().is_empty() {
+            return Ok(ComplexVector::new(Vec::new()));
+        }
+
+        inner.split(',')
+            .enumerate()
+            .map(|(i, element)| {
+                element.parse::<Complex>().map_err(|e| format!("Invalid element {}: {}", i, e))
+            })
+            .collect()
+    }
+}
+
 /// Implement vector addition
 impl Add for ComplexVector {
     type Output = ComplexVector;
@@ -335,6 +1323,67 @@ impl Mul<ComplexVector> for f64 {
     }
 }
 
+/// Implement scalar division (vector / scalar)
+/// 
+/// Division by zero follows IEEE semantics and produces infinite or NaN components.
+impl Div<f64> for ComplexVector {
+    type Output = ComplexVector;
+
+    fn div(self, scalar: f64) -> ComplexVector {
+        let mut result = Vec::with_capacity(self.dimension());
+        for i in 0..self.dimension() {
+            result.push(self.components[i] / scalar);
+        }
+        ComplexVector::new(result)
+    }
+}
+
+/// Implement complex scalar division (vector / complex)
+/// 
+/// Division by zero follows IEEE semantics and produces infinite or NaN components.
+impl Div<Complex> for ComplexVector {
+    type Output = ComplexVector;
+
+    fn div(self, scalar: Complex) -> ComplexVector {
+        let mut result = Vec::with_capacity(self.dimension());
+        for i in 0..self.dimension() {
+            result.push(self.components[i] / scalar);
+        }
+        ComplexVector::new(result)
+    }
+}
+
+/// Implement the Hadamard (element-wise) product (&vector * &vector)
+impl Mul<&ComplexVector> for &ComplexVector {
+    type Output = ComplexVector;
+
+    fn mul(self, other: &ComplexVector) -> ComplexVector {
+        self.hadamard(other)
+    }
+}
+
+/// Implement complex scalar multiplication (vector * complex)
+impl Mul<Complex> for ComplexVector {
+    type Output = ComplexVector;
+
+    fn mul(self, scalar: Complex) -> ComplexVector {
+        let mut result = Vec::with_capacity(self.dimension());
+        for i in 0..self.dimension() {
+            result.push(self.components[i] * scalar);
+        }
+        ComplexVector::new(result)
+    }
+}
+
+/// Implement complex scalar multiplication (complex * vector)
+impl Mul<ComplexVector> for Complex {
+    type Output = ComplexVector;
+
+    fn mul(self, vector: ComplexVector) -> ComplexVector {
+        vector * self
+    }
+}
+
 /// Implement vector negation
 impl Neg for ComplexVector {
     type Output = ComplexVector;
@@ -3,9 +3,11 @@
 //! This module provides a generic matrix implementation that supports both real numbers (f64)
 //! and complex numbers (Complex).
 
-use std::ops::{Add, Sub, Mul, Neg};
+use std::ops::{Add, Sub, Mul, Div, Neg, Index, IndexMut};
 use std::fmt;
-use crate::complex::{Complex, ComplexVector};
+use std::ops::Range;
+use crate::complex::{Angle, Complex, ComplexVector, Float, GenericComplex};
+use crate::linalg::Vector;
 
 /// A matrix that can contain either real numbers (f64) or complex numbers (Complex)
 #[derive(Clone, PartialEq)]
@@ -43,6 +45,86 @@ impl<T> Matrix<T> {
         Matrix { rows, cols, data }
     }
 
+    /// Creates a new matrix from a vector of rows
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the rows do not all have the same length
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::from_rows(vec![
+    ///     vec![1.0, 2.0, 3.0],
+    ///     vec![4.0, 5.0, 6.0],
+    /// ]);
+    /// assert_eq!(matrix.rows(), 2);
+    /// assert_eq!(matrix.cols(), 3);
+    /// assert_eq!(matrix.get(1, 0), &4.0);
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let num_rows = rows.len();
+        let num_cols = rows.first().map_or(0, |row| row.len());
+        assert!(rows.iter().all(|row| row.len() == num_cols), "All rows must have the same length");
+
+        let data = rows.into_iter().flatten().collect();
+        Matrix::new(num_rows, num_cols, data)
+    }
+
+    /// Creates a new matrix from a vector of columns
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the columns do not all have the same length
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::from_cols(vec![
+    ///     vec![1.0, 2.0, 3.0],
+    ///     vec![4.0, 5.0, 6.0],
+    /// ]);
+    /// assert_eq!(matrix.rows(), 3);
+    /// assert_eq!(matrix.cols(), 2);
+    /// assert_eq!(matrix.get(0, 1), &4.0);
+    /// ```
+    pub fn from_cols(cols: Vec<Vec<T>>) -> Self {
+        let num_cols = cols.len();
+        let num_rows = cols.first().map_or(0, |col| col.len());
+        assert!(cols.iter().all(|col| col.len() == num_rows), "All columns must have the same length");
+
+        let mut columns: Vec<_> = cols.into_iter().map(|col| col.into_iter()).collect();
+        let mut data = Vec::with_capacity(num_rows * num_cols);
+        for _ in 0..num_rows {
+            for column in columns.iter_mut() {
+                data.push(column.next().unwrap());
+            }
+        }
+        Matrix::new(num_rows, num_cols, data)
+    }
+
+    /// Returns the matrix as a vector of rows
+    /// 
+    /// This is the inverse of `from_rows`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(matrix.to_rows(), vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// ```
+    pub fn to_rows(&self) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        (0..self.rows)
+            .map(|i| self.data[i * self.cols..(i + 1) * self.cols].to_vec())
+            .collect()
+    }
+
     /// Creates a new matrix filled with zeros
     /// 
     /// # Example
@@ -54,13 +136,38 @@ impl<T> Matrix<T> {
     /// ```
     pub fn zeros(rows: usize, cols: usize) -> Self 
     where
-        T: Default + Clone,
+        T: Zero + Clone,
     {
         Matrix {
             rows,
             cols,
-            data: vec![T::default(); rows * cols],
+            data: vec![T::zero(); rows * cols],
+        }
+    }
+
+    /// Creates an identity matrix of the given size
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let identity: Matrix<Complex> = Matrix::identity(2);
+    /// assert_eq!(identity.get(0, 0), &Complex::new(1.0, 0.0));
+    /// assert_eq!(identity.get(1, 1), &Complex::new(1.0, 0.0));
+    /// 
+    /// let real: Matrix<f64> = Matrix::identity(2);
+    /// assert_eq!(real.get(0, 1), &0.0);
+    /// ```
+    pub fn identity(size: usize) -> Self
+    where
+        T: Zero + One + Clone,
+    {
+        let mut result = Matrix::zeros(size, size);
+        for i in 0..size {
+            result.set(i, i, T::one());
         }
+        result
     }
 
     /// Gets the element at the specified position
@@ -130,210 +237,2278 @@ impl<T> Matrix<T> {
     pub fn cols(&self) -> usize {
         self.cols
     }
-}
-
-impl<T: fmt::Debug> fmt::Debug for Matrix<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "Matrix({}x{})", self.rows, self.cols)?;
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                write!(f, "{:?} ", self.get(row, col))?;
-            }
-            writeln!(f)?;
-        }
-        Ok(())
-    }
-}
-
-// Matrix addition
-impl<T: Add<Output = T> + Clone + Default> Add for Matrix<T> {
-    type Output = Matrix<T>;
 
-    fn add(self, other: Matrix<T>) -> Matrix<T> {
-        assert_eq!(self.rows, other.rows, "Matrices must have same number of rows");
-        assert_eq!(self.cols, other.cols, "Matrices must have same number of columns");
-        
-        let mut result = Matrix::zeros(self.rows, self.cols);
-        for i in 0..self.data.len() {
-            result.data[i] = self.data[i].clone() + other.data[i].clone();
-        }
-        result
+    /// Applies a function to every element, producing a new matrix
+    /// 
+    /// The element type may change, e.g. to promote a real matrix to a complex one.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(1, 2, vec![1.0, 2.0]);
+    /// let complex = matrix.map(|&x| Complex::new(0.0, x));
+    /// assert_eq!(complex.get(0, 1), &Complex::new(0.0, 2.0));
+    /// ```
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Matrix<U> {
+        Matrix::new(self.rows, self.cols, self.data.iter().map(f).collect())
     }
-}
-
-// Matrix subtraction
-impl<T: Sub<Output = T> + Clone + Default> Sub for Matrix<T> {
-    type Output = Matrix<T>;
 
-    fn sub(self, other: Matrix<T>) -> Matrix<T> {
-        assert_eq!(self.rows, other.rows, "Matrices must have same number of rows");
-        assert_eq!(self.cols, other.cols, "Matrices must have same number of columns");
-        
-        let mut result = Matrix::zeros(self.rows, self.cols);
-        for i in 0..self.data.len() {
-            result.data[i] = self.data[i].clone() - other.data[i].clone();
+    /// Computes the transpose of the matrix
+    /// 
+    /// Rows and columns are swapped without conjugating the elements. For the
+    /// conjugate transpose of a complex matrix use `conjugate_transpose`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let transposed = matrix.transpose();
+    /// assert_eq!(transposed.rows(), 3);
+    /// assert_eq!(transposed.cols(), 2);
+    /// assert_eq!(transposed.get(2, 0), &3.0);
+    /// ```
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.data.len());
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                data.push(self.get(row, col).clone());
+            }
         }
-        result
+        Matrix::new(self.cols, self.rows, data)
     }
-}
 
-// Matrix negation
-impl<T: Neg<Output = T> + Clone + Default> Neg for Matrix<T> {
-    type Output = Matrix<T>;
+    /// Extracts a copy of the block covering the given row and column ranges
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if either range is reversed or extends past the matrix bounds
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// let block = matrix.submatrix(1..3, 0..2);
+    /// assert_eq!(block, Matrix::new(2, 2, vec![4.0, 5.0, 7.0, 8.0]));
+    /// ```
+    pub fn submatrix(&self, row_range: Range<usize>, col_range: Range<usize>) -> Self
+    where
+        T: Clone,
+    {
+        assert!(
+            row_range.start <= row_range.end && row_range.end <= self.rows,
+            "Row range {:?} out of bounds for matrix with {} rows", row_range, self.rows
+        );
+        assert!(
+            col_range.start <= col_range.end && col_range.end <= self.cols,
+            "Column range {:?} out of bounds for matrix with {} columns", col_range, self.cols
+        );
 
-    fn neg(self) -> Matrix<T> {
-        let mut result = Matrix::zeros(self.rows, self.cols);
-        for i in 0..self.data.len() {
-            result.data[i] = -self.data[i].clone();
+        let mut data = Vec::with_capacity(row_range.len() * col_range.len());
+        for row in row_range.clone() {
+            data.extend_from_slice(&self.data[row * self.cols + col_range.start..row * self.cols + col_range.end]);
         }
-        result
+        Matrix::new(row_range.len(), col_range.len(), data)
     }
-}
 
+    /// Returns the minor matrix with the given row and column removed
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the row or column index is out of bounds
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    /// let minor = matrix.minor(1, 1);
+    /// assert_eq!(minor, Matrix::new(2, 2, vec![1.0, 3.0, 7.0, 9.0]));
+    /// ```
+    pub fn minor(&self, row: usize, col: usize) -> Self
+    where
+        T: Clone,
+    {
+        assert!(row < self.rows, "Row index {} out of bounds for matrix with {} rows", row, self.rows);
+        assert!(col < self.cols, "Column index {} out of bounds for matrix with {} columns", col, self.cols);
 
-// Matrix multiplication
-impl Mul<&Matrix<Complex>> for &Matrix<Complex> {
-    type Output = Matrix<Complex>;
-
-    fn mul(self, other: &Matrix<Complex>) -> Matrix<Complex> {
-        assert_eq!(self.cols, other.rows, "Number of columns in first matrix must match number of rows in second matrix");
-        
-        let mut result = Matrix::zeros(self.rows, other.cols);
-        for i in 0..self.rows {
-            for j in 0..other.cols {
-                let mut sum = Complex::new(0.0, 0.0);
-                for k in 0..self.cols {
-                    sum = sum + *self.get(i, k) * *other.get(k, j);
-                }
-                result.set(i, j, sum);
+        let mut data = Vec::with_capacity((self.rows - 1) * (self.cols - 1));
+        for i in (0..self.rows).filter(|&i| i != row) {
+            for j in (0..self.cols).filter(|&j| j != col) {
+                data.push(self.get(i, j).clone());
             }
         }
-        result
+        Matrix::new(self.rows - 1, self.cols - 1, data)
     }
-}
 
-// Special implementations for Complex numbers
-impl Matrix<Complex> {
-    /// Creates an identity matrix of the given size
+    /// Concatenates another matrix to the right of this one, forming `[self | other]`
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrices have different numbers of rows
     /// 
     /// # Example
     /// ```rust
     /// use rusticle::linalg::Matrix;
-    /// use rusticle::complex::Complex;
     /// 
-    /// let identity = Matrix::identity(2);
-    /// assert_eq!(identity.get(0, 0), &Complex::new(1.0, 0.0));
-    /// assert_eq!(identity.get(1, 1), &Complex::new(1.0, 0.0));
+    /// let a = Matrix::new(2, 1, vec![1.0, 2.0]);
+    /// let b = Matrix::new(2, 2, vec![3.0, 4.0, 5.0, 6.0]);
+    /// assert_eq!(a.hstack(&b), Matrix::new(2, 3, vec![1.0, 3.0, 4.0, 2.0, 5.0, 6.0]));
     /// ```
-    pub fn identity(size: usize) -> Self {
-        let mut result = Matrix::zeros(size, size);
-        for i in 0..size {
-            result.set(i, i, Complex::new(1.0, 0.0));
+    pub fn hstack(&self, other: &Matrix<T>) -> Self
+    where
+        T: Clone,
+    {
+        assert_eq!(self.rows, other.rows, "Matrices must have same number of rows");
+
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        for row in 0..self.rows {
+            data.extend_from_slice(&self.data[row * self.cols..(row + 1) * self.cols]);
+            data.extend_from_slice(&other.data[row * other.cols..(row + 1) * other.cols]);
         }
-        result
+        Matrix::new(self.rows, self.cols + other.cols, data)
     }
 
-    /// Multiplies this matrix by a vector in-place, modifying the matrix
+    /// Concatenates another matrix below this one
     /// 
     /// # Panics
     /// 
-    /// Panics if the number of columns in the matrix does not match the vector dimension
+    /// Panics if the matrices have different numbers of columns
     /// 
     /// # Example
     /// ```rust
     /// use rusticle::linalg::Matrix;
-    /// use rusticle::complex::{Complex, ComplexVector};
     /// 
-    /// let mut matrix = Matrix::new(2, 2, vec![
-    ///     Complex::new(2.0, 0.0), Complex::new(3.0, 0.0),
-    ///     Complex::new(1.0, 0.0), Complex::new(4.0, 0.0)
-    /// ]);
+    /// let a = Matrix::new(1, 2, vec![1.0, 2.0]);
+    /// let b = Matrix::new(2, 2, vec![3.0, 4.0, 5.0, 6.0]);
+    /// assert_eq!(a.vstack(&b), Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+    /// ```
+    pub fn vstack(&self, other: &Matrix<T>) -> Self
+    where
+        T: Clone,
+    {
+        assert_eq!(self.cols, other.cols, "Matrices must have same number of columns");
+
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        data.extend_from_slice(&self.data);
+        data.extend_from_slice(&other.data);
+        Matrix::new(self.rows + other.rows, self.cols, data)
+    }
+
+    /// Swaps two rows of the matrix in place
     /// 
-    /// let vector = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
-    /// matrix.mul_vector(&vector);
+    /// # Panics
     /// 
-    /// assert_eq!(matrix.get(0, 0), &Complex::new(8.0, 0.0));  // 2*1 + 3*2 = 8
-    /// assert_eq!(matrix.get(1, 0), &Complex::new(9.0, 0.0));  // 1*1 + 4*2 = 9
+    /// Panics if either row index is out of bounds
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let mut matrix = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// matrix.swap_rows(0, 1);
+    /// assert_eq!(matrix, Matrix::new(2, 2, vec![3.0, 4.0, 1.0, 2.0]));
     /// ```
-    pub fn mul_vector(&mut self, vector: &ComplexVector) {
-        assert_eq!(self.cols(), vector.dimension(), "Matrix columns must match vector dimension");
-        
-        let mut result = vec![Complex::new(0.0, 0.0); self.rows()];
-        for i in 0..self.rows() {
-            for j in 0..self.cols() {
-                result[i] = result[i] + *self.get(i, j) * vector.components[j];
-            }
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.rows, "Row index {} out of bounds for matrix with {} rows", a, self.rows);
+        assert!(b < self.rows, "Row index {} out of bounds for matrix with {} rows", b, self.rows);
+
+        for col in 0..self.cols {
+            self.data.swap(a * self.cols + col, b * self.cols + col);
         }
-        
-        // Update matrix dimensions and data
-        self.cols = 1;
-        self.data = result;
     }
 
-    /// Computes the conjugate transpose of the matrix
+    /// Multiplies every element of a row by a factor in place
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the row index is out of bounds
     /// 
     /// # Example
     /// ```rust
     /// use rusticle::linalg::Matrix;
-    /// use rusticle::complex::Complex;
-    /// 
-    /// let matrix = Matrix::new(2, 2, vec![
-    ///     Complex::new(1.0, 2.0),
-    ///     Complex::new(3.0, 4.0),
-    ///     Complex::new(5.0, 6.0),
-    ///     Complex::new(7.0, 8.0)
-    /// ]);
     /// 
-    /// let conjugate_transpose = matrix.conjugate_transpose();
-    /// assert_eq!(conjugate_transpose.get(0, 0), &Complex::new(1.0, -2.0));
+    /// let mut matrix = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// matrix.scale_row(1, 0.5);
+    /// assert_eq!(matrix, Matrix::new(2, 2, vec![1.0, 2.0, 1.5, 2.0]));
     /// ```
-    pub fn conjugate_transpose(&self) -> Self {
-        let mut result = Matrix::zeros(self.cols, self.rows);
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                result.set(col, row, self.get(row, col).conjugate());
-            }
+    pub fn scale_row(&mut self, row: usize, factor: T)
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        assert!(row < self.rows, "Row index {} out of bounds for matrix with {} rows", row, self.rows);
+
+        for col in 0..self.cols {
+            let index = row * self.cols + col;
+            self.data[index] = self.data[index].clone() * factor.clone();
         }
-        result
     }
 
-    /// Checks if the matrix is unitary
+    /// Adds a multiple of the source row to the target row in place
     /// 
-    /// A matrix is unitary if its conjugate transpose is its inverse
+    /// # Panics
+    /// 
+    /// Panics if either row index is out of bounds
     /// 
     /// # Example
     /// ```rust
     /// use rusticle::linalg::Matrix;
-    /// use rusticle::complex::Complex;
-    /// 
-    /// let unitary = Matrix::new(2, 2, vec![
-    ///     Complex::new(1.0 / 2.0f64.sqrt(), 0.0),
-    ///     Complex::new(1.0 / 2.0f64.sqrt(), 0.0),
-    ///     Complex::new(1.0 / 2.0f64.sqrt(), 0.0),
-    ///     Complex::new(-1.0 / 2.0f64.sqrt(), 0.0)
-    /// ]);
     /// 
-    /// assert!(unitary.is_unitary());
+    /// let mut matrix = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// matrix.add_scaled_row(1, 0, -3.0);
+    /// assert_eq!(matrix, Matrix::new(2, 2, vec![1.0, 2.0, 0.0, -2.0]));
     /// ```
-    pub fn is_unitary(&self) -> bool {
-        if self.rows != self.cols {
-            return false;
-        }
+    pub fn add_scaled_row(&mut self, target: usize, source: usize, factor: T)
+    where
+        T: Add<Output = T> + Mul<Output = T> + Clone,
+    {
+        assert!(target < self.rows, "Row index {} out of bounds for matrix with {} rows", target, self.rows);
+        assert!(source < self.rows, "Row index {} out of bounds for matrix with {} rows", source, self.rows);
 
-        let size = self.rows;
-        let identity = Matrix::identity(size);
-        let product = self * &self.conjugate_transpose();
-        
-        // Check if product is approximately equal to identity matrix
-        for i in 0..size {
-            for j in 0..size {
-                let diff = *product.get(i, j) - *identity.get(i, j);
-                if diff.magnitude() > 1e-10 {
-                    return false;
-                }
-            }
+        for col in 0..self.cols {
+            let scaled = self.data[source * self.cols + col].clone() * factor.clone();
+            let index = target * self.cols + col;
+            self.data[index] = self.data[index].clone() + scaled;
         }
-        true
     }
-}
 
+    /// Computes the trace of the matrix (the sum of its diagonal elements)
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(matrix.trace(), 5.0);
+    /// ```
+    pub fn trace(&self) -> T
+    where
+        T: Add<Output = T> + Zero + Clone,
+    {
+        assert_eq!(self.rows, self.cols, "Trace is only defined for square matrices");
+
+        let mut sum = T::zero();
+        for i in 0..self.rows {
+            sum = sum + self.get(i, i).clone();
+        }
+        sum
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Matrix({}x{})", self.rows, self.cols)?;
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                write!(f, "{:?} ", self.get(row, col))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+// Element access via m[(row, col)]
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "Index ({}, {}) out of bounds for {}x{} matrix", row, col, self.rows, self.cols
+        );
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.rows && col < self.cols,
+            "Index ({}, {}) out of bounds for {}x{} matrix", row, col, self.rows, self.cols
+        );
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+/// Displays the matrix one row per line with right-aligned columns
+/// 
+/// Each column is padded to its widest entry so numbers line up. The
+/// formatter's precision, if given, is applied to every element.
+/// 
+/// # Example
+/// ```rust
+/// use rusticle::linalg::Matrix;
+/// 
+/// let matrix = Matrix::new(2, 2, vec![1.0, 2.5, 10.0, -3.0]);
+/// assert_eq!(format!("{}", matrix), "[ 1 2.5]\n[10  -3]");
+/// assert_eq!(format!("{:.1}", matrix), "[ 1.0  2.5]\n[10.0 -3.0]");
+/// ```
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<String> = self.data.iter()
+            .map(|value| match f.precision() {
+                Some(precision) => format!("{:.*}", precision, value),
+                None => format!("{}", value),
+            })
+            .collect();
+
+        let mut widths = vec![0; self.cols];
+        for (i, cell) in cells.iter().enumerate() {
+            let col = i % self.cols;
+            widths[col] = widths[col].max(cell.chars().count());
+        }
+
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for col in 0..self.cols {
+                if col > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{:>width$}", cells[row * self.cols + col], width = widths[col])?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+// Matrix addition
+//
+// The borrowed form clones each element of both operands, which is free for
+// `Copy` element types. Other element types should use the owned form.
+impl<T: Add<Output = T> + Clone> Add<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.rows, other.rows, "Matrices must have same number of rows");
+        assert_eq!(self.cols, other.cols, "Matrices must have same number of columns");
+
+        let data = self.data.iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a.clone() + b.clone())
+            .collect();
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other: Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.rows, other.rows, "Matrices must have same number of rows");
+        assert_eq!(self.cols, other.cols, "Matrices must have same number of columns");
+
+        // Moves both operands' elements; the collect reuses the left buffer
+        let data = self.data.into_iter()
+            .zip(other.data)
+            .map(|(a, b)| a + b)
+            .collect();
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+// Matrix subtraction
+//
+// The borrowed form clones each element of both operands, which is free for
+// `Copy` element types. Other element types should use the owned form.
+impl<T: Sub<Output = T> + Clone> Sub<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.rows, other.rows, "Matrices must have same number of rows");
+        assert_eq!(self.cols, other.cols, "Matrices must have same number of columns");
+
+        let data = self.data.iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a.clone() - b.clone())
+            .collect();
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.rows, other.rows, "Matrices must have same number of rows");
+        assert_eq!(self.cols, other.cols, "Matrices must have same number of columns");
+
+        // Moves both operands' elements; the collect reuses the left buffer
+        let data = self.data.into_iter()
+            .zip(other.data)
+            .map(|(a, b)| a - b)
+            .collect();
+        Matrix::new(self.rows, self.cols, data)
+    }
+}
+
+// Matrix negation
+impl<T: Neg<Output = T>> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Matrix<T> {
+        self.data = self.data.into_iter().map(|x| -x).collect();
+        self
+    }
+}
+
+
+// Matrix multiplication
+impl Mul<&Matrix<Complex>> for &Matrix<Complex> {
+    type Output = Matrix<Complex>;
+
+    fn mul(self, other: &Matrix<Complex>) -> Matrix<Complex> {
+        assert_eq!(self.cols, other.rows, "Number of columns in first matrix must match number of rows in second matrix");
+        
+        let mut result = Matrix::zeros(self.rows, other.cols);
+        if other.cols == 0 {
+            return result;
+        }
+
+        // Each output row depends only on one row of `self`, so rows can be
+        // computed independently and, with the `rayon` feature, in parallel
+        #[cfg(feature = "rayon")]
+        {
+            use rayon::prelude::*;
+            result.data
+                .par_chunks_mut(other.cols)
+                .enumerate()
+                .for_each(|(i, row)| complex_row_product(self, other, i, row));
+        }
+        #[cfg(not(feature = "rayon"))]
+        for (i, row) in result.data.chunks_mut(other.cols).enumerate() {
+            complex_row_product(self, other, i, row);
+        }
+        result
+    }
+}
+
+/// Accumulates row `i` of the product `a * b` into the zeroed `row`
+/// 
+/// Uses `i-k-j` loop order so the inner loop walks contiguous rows of `b`.
+/// Each entry still sums its terms in increasing `k`, so results match the
+/// naive `i-j-k` order exactly.
+fn complex_row_product(a: &Matrix<Complex>, b: &Matrix<Complex>, i: usize, row: &mut [Complex]) {
+    for k in 0..a.cols {
+        let a_ik = a.data[i * a.cols + k];
+        let b_row = &b.data[k * b.cols..(k + 1) * b.cols];
+        for (entry, &b_kj) in row.iter_mut().zip(b_row) {
+            *entry += a_ik * b_kj;
+        }
+    }
+}
+
+impl Mul<&Matrix<f64>> for &Matrix<f64> {
+    type Output = Matrix<f64>;
+
+    fn mul(self, other: &Matrix<f64>) -> Matrix<f64> {
+        assert_eq!(self.cols, other.rows, "Number of columns in first matrix must match number of rows in second matrix");
+        
+        let mut result = Matrix::zeros(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a_ik = self.data[i * self.cols + k];
+                for j in 0..other.cols {
+                    result.data[i * other.cols + j] += a_ik * other.data[k * other.cols + j];
+                }
+            }
+        }
+        result
+    }
+}
+
+// Matrix-vector multiplication
+impl Mul<&ComplexVector> for &Matrix<Complex> {
+    type Output = ComplexVector;
+
+    fn mul(self, vector: &ComplexVector) -> ComplexVector {
+        assert_eq!(self.cols, vector.dimension(), "Matrix columns must match vector dimension");
+
+        let mut result = vec![Complex::new(0.0, 0.0); self.rows];
+        for (i, entry) in result.iter_mut().enumerate() {
+            for j in 0..self.cols {
+                *entry += *self.get(i, j) * vector.components[j];
+            }
+        }
+        ComplexVector::new(result)
+    }
+}
+
+// Scalar multiplication
+impl Mul<f64> for Matrix<f64> {
+    type Output = Matrix<f64>;
+
+    fn mul(mut self, scalar: f64) -> Matrix<f64> {
+        for value in self.data.iter_mut() {
+            *value *= scalar;
+        }
+        self
+    }
+}
+
+impl Mul<f64> for Matrix<Complex> {
+    type Output = Matrix<Complex>;
+
+    fn mul(mut self, scalar: f64) -> Matrix<Complex> {
+        for value in self.data.iter_mut() {
+            *value *= scalar;
+        }
+        self
+    }
+}
+
+impl Mul<Complex> for Matrix<Complex> {
+    type Output = Matrix<Complex>;
+
+    fn mul(mut self, scalar: Complex) -> Matrix<Complex> {
+        for value in self.data.iter_mut() {
+            *value *= scalar;
+        }
+        self
+    }
+}
+
+// Special implementations for real numbers
+impl Matrix<f64> {
+    /// Returns the row at the given index as a vector
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the row index is out of bounds
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::{Matrix, Vector};
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(matrix.row(1), Vector::new(vec![3.0, 4.0]));
+    /// ```
+    pub fn row(&self, i: usize) -> Vector {
+        assert!(i < self.rows, "Row index {} out of bounds for matrix with {} rows", i, self.rows);
+        Vector::new(self.data[i * self.cols..(i + 1) * self.cols].to_vec())
+    }
+
+    /// Returns the column at the given index as a vector
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the column index is out of bounds
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::{Matrix, Vector};
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert_eq!(matrix.col(1), Vector::new(vec![2.0, 4.0]));
+    /// ```
+    pub fn col(&self, j: usize) -> Vector {
+        assert!(j < self.cols, "Column index {} out of bounds for matrix with {} columns", j, self.cols);
+        Vector::new((0..self.rows).map(|i| *self.get(i, j)).collect())
+    }
+
+    /// Computes the Frobenius norm `sqrt(sum a_ij^2)` of the matrix
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+    /// assert_eq!(matrix.frobenius_norm(), 5.0);
+    /// ```
+    pub fn frobenius_norm(&self) -> f64 {
+        self.data.iter().map(|x| x * x).sum::<f64>().sqrt()
+    }
+
+    /// Computes the Frobenius distance `||self - other||_F` between two matrices
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrices have different dimensions
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let a = Matrix::new(1, 2, vec![1.0, 2.0]);
+    /// let b = Matrix::new(1, 2, vec![4.0, 6.0]);
+    /// assert_eq!(a.frobenius_distance(&b), 5.0);
+    /// ```
+    pub fn frobenius_distance(&self, other: &Matrix<f64>) -> f64 {
+        assert_eq!(self.rows, other.rows, "Matrices must have same number of rows");
+        assert_eq!(self.cols, other.cols, "Matrices must have same number of columns");
+
+        self.data.iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Checks if two matrices are equal within a tolerance
+    /// 
+    /// Returns `false` if the dimensions differ, and otherwise compares each pair
+    /// of elements by `|a - b| <= tol`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let a = Matrix::new(1, 2, vec![1.0, 2.0]);
+    /// let b = Matrix::new(1, 2, vec![1.0 + 1e-12, 2.0]);
+    /// assert!(a != b);
+    /// assert!(a.approx_eq(&b, 1e-10));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix<f64>, tol: f64) -> bool {
+        approx_eq(self, other, tol)
+    }
+
+    /// Computes the Cholesky decomposition of a symmetric positive-definite matrix
+    /// 
+    /// Returns the lower triangular matrix `L` with `L * Lᵀ` equal to the original
+    /// matrix, or `None` if the matrix is not square, not symmetric, or not
+    /// positive-definite.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![4.0, 2.0, 2.0, 5.0]);
+    /// let l = a.cholesky().unwrap();
+    /// assert_eq!(l, Matrix::new(2, 2, vec![2.0, 0.0, 1.0, 2.0]));
+    /// 
+    /// let indefinite = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 1.0]);
+    /// assert!(indefinite.cholesky().is_none());
+    /// ```
+    pub fn cholesky(&self) -> Option<Matrix<f64>> {
+        if !self.is_symmetric(SINGULARITY_TOLERANCE * max_modulus(&self.data)) {
+            return None;
+        }
+
+        let n = self.rows;
+        let mut l = Matrix::zeros(n, n);
+        for j in 0..n {
+            let diagonal = self.get(j, j) - (0..j).map(|k| l.get(j, k) * l.get(j, k)).sum::<f64>();
+            if diagonal <= 0.0 {
+                return None;
+            }
+            let pivot = diagonal.sqrt();
+            l.set(j, j, pivot);
+
+            for i in (j + 1)..n {
+                let sum = (0..j).map(|k| l.get(i, k) * l.get(j, k)).sum::<f64>();
+                l.set(i, j, (self.get(i, j) - sum) / pivot);
+            }
+        }
+        Some(l)
+    }
+
+    /// Computes the QR decomposition of the matrix using Householder reflections
+    /// 
+    /// Returns `(Q, R)` where `Q` is an `m x m` orthogonal matrix and `R` is an
+    /// `m x n` upper triangular matrix with `QR` equal to the original matrix.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let a = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let (q, r) = a.qr();
+    /// assert_eq!(r.get(1, 0), &0.0);
+    /// 
+    /// let product = &q * &r;
+    /// assert!((product.get(2, 1) - 6.0).abs() < 1e-10);
+    /// ```
+    pub fn qr(&self) -> (Matrix<f64>, Matrix<f64>) {
+        householder_qr(self)
+    }
+
+    /// Computes all eigenvalues of the matrix using the shifted QR algorithm
+    /// 
+    /// The matrix is promoted to a complex matrix and solved with
+    /// `Matrix<Complex>::eigenvalues`, so eigenvalues of non-symmetric real
+    /// matrices may be complex.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+    /// let eigenvalues = matrix.eigenvalues(100, 1e-12);
+    /// assert!(eigenvalues.iter().all(|l| l.real.abs() < 1e-10 && (l.imag.abs() - 1.0).abs() < 1e-10));
+    /// ```
+    pub fn eigenvalues(&self, max_iter: usize, tol: f64) -> Vec<Complex> {
+        self.map(|&x| Complex::from(x)).eigenvalues(max_iter, tol)
+    }
+
+    /// Finds the largest-magnitude eigenvalue and its eigenvector by power iteration
+    /// 
+    /// Starting from a fixed pseudo-random unit vector, the matrix is applied
+    /// repeatedly and the eigenvalue is estimated by the Rayleigh quotient. An
+    /// unstructured start avoids beginning on an eigenvector of a smaller
+    /// eigenvalue, as the all-ones vector is for many symmetric matrices. The
+    /// iteration stops once the residual `||Av - λv||` of the unit vector `v` is
+    /// at most `tol`, returning `(λ, v)`. Returns `None` if this does not happen
+    /// within `max_iter` iterations, which is the case when two eigenvalues of
+    /// equal magnitude compete for dominance.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![2.0, 0.0, 0.0, 5.0]);
+    /// let (eigenvalue, eigenvector) = matrix.dominant_eigenpair(1000, 1e-10).unwrap();
+    /// assert!((eigenvalue - 5.0).abs() < 1e-10);
+    /// assert!((eigenvector.components[1].abs() - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn dominant_eigenpair(&self, max_iter: usize, tol: f64) -> Option<(f64, Vector)> {
+        assert_eq!(self.rows, self.cols, "Power iteration is only defined for square matrices");
+
+        let n = self.rows;
+        if n == 0 {
+            return None;
+        }
+        let apply = |v: &Vector| {
+            Vector::new((0..n)
+                .map(|i| (0..n).map(|j| self.get(i, j) * v.components[j]).sum())
+                .collect())
+        };
+
+        // Linear congruential sequence in [-1, 1), which is never the zero vector
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let start = (0..n)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+            })
+            .collect();
+        let mut v = Vector::new(start).normalize();
+        for _ in 0..max_iter {
+            let av = apply(&v);
+            let eigenvalue = v.dot(&av);
+            if (av.clone() - v.clone() * eigenvalue).norm() <= tol {
+                return Some((eigenvalue, v));
+            }
+
+            let norm = av.norm();
+            if norm == 0.0 {
+                return None;
+            }
+            v = av * (1.0 / norm);
+        }
+        None
+    }
+
+    /// Checks if the matrix is symmetric within a tolerance
+    /// 
+    /// A matrix is symmetric if it equals its own transpose. Non-square matrices
+    /// are never symmetric.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let symmetric = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 3.0]);
+    /// assert!(symmetric.is_symmetric(1e-10));
+    /// 
+    /// let asymmetric = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// assert!(!asymmetric.is_symmetric(1e-10));
+    /// ```
+    pub fn is_symmetric(&self, tol: f64) -> bool {
+        self.approx_eq(&self.transpose(), tol)
+    }
+
+    /// Computes the determinant of the matrix
+    /// 
+    /// 2x2 and 3x3 matrices use the closed-form cofactor expansion. Larger
+    /// matrices are reduced by Gaussian elimination with partial pivoting,
+    /// which scales far better than cofactor expansion.
+    /// Singular matrices yield zero (or a value very close to zero).
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![4.0, 3.0, 6.0, 3.0]);
+    /// assert!((matrix.determinant() - -6.0).abs() < 1e-10);
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        determinant(self)
+    }
+
+    /// Computes the inverse of the matrix
+    /// 
+    /// The inverse is computed from an LU decomposition with partial pivoting.
+    /// Returns `None` if the matrix is singular, i.e. if a pivot is smaller than
+    /// `1e-12` times the largest element of the matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![4.0, 7.0, 2.0, 6.0]);
+    /// let inverse = matrix.inverse().unwrap();
+    /// assert!((inverse.get(0, 0) - 0.6).abs() < 1e-10);
+    /// assert!((inverse.get(0, 1) - -0.7).abs() < 1e-10);
+    /// 
+    /// let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+    /// assert!(singular.inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        inverse(self)
+    }
+
+    /// Checks whether the matrix is singular to within an absolute pivot tolerance
+    /// 
+    /// Performs Gaussian elimination with partial pivoting and reports `true` as
+    /// soon as a pivot magnitude is at most `tol`. Unlike testing the determinant,
+    /// this never forms the product of the pivots, so it cannot overflow or
+    /// underflow on large matrices.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![4.0, 7.0, 2.0, 6.0]);
+    /// assert!(!matrix.is_singular(1e-10));
+    /// 
+    /// let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+    /// assert!(singular.is_singular(1e-10));
+    /// ```
+    pub fn is_singular(&self, tol: f64) -> bool {
+        assert_eq!(self.rows, self.cols, "Singularity check is only defined for square matrices");
+        LuFactors::new(self, tol).is_none()
+    }
+
+    /// Estimates the condition number of the matrix in the 1-norm
+    /// 
+    /// Computes `||A||_1 * ||A^-1||_1`, where the 1-norm is the largest absolute
+    /// column sum and the inverse columns are obtained from the LU factors.
+    /// Values near 1 indicate a well-conditioned matrix, while very large values
+    /// warn that solving with it will lose precision. Returns `f64::INFINITY`
+    /// for an exactly singular matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let identity = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, 1.0]);
+    /// assert_eq!(identity.condition_estimate(), 1.0);
+    /// 
+    /// let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+    /// assert_eq!(singular.condition_estimate(), f64::INFINITY);
+    /// ```
+    pub fn condition_estimate(&self) -> f64 {
+        assert_eq!(self.rows, self.cols, "Condition number is only defined for square matrices");
+
+        let n = self.rows;
+        let factors = match LuFactors::new(self, 0.0) {
+            Some(factors) => factors,
+            None => return f64::INFINITY,
+        };
+
+        let norm = (0..n)
+            .map(|j| (0..n).map(|i| self.get(i, j).abs()).sum::<f64>())
+            .fold(0.0, f64::max);
+
+        let mut inverse_norm: f64 = 0.0;
+        let mut unit = vec![0.0; n];
+        for j in 0..n {
+            unit[j] = 1.0;
+            let column = factors.solve(&unit);
+            inverse_norm = inverse_norm.max(column.iter().map(|x| x.abs()).sum());
+            unit[j] = 0.0;
+        }
+        norm * inverse_norm
+    }
+
+    /// Computes the LU decomposition of the matrix with partial pivoting
+    /// 
+    /// Returns `(L, U, permutation)` such that `PA = LU`, where `L` is unit lower
+    /// triangular, `U` is upper triangular, and row `i` of `PA` is row
+    /// `permutation[i]` of `A`. The factors can be reused across multiple
+    /// right-hand sides. Returns `None` if the matrix is singular.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    /// let (l, u, permutation) = a.lu().unwrap();
+    /// 
+    /// // The larger pivot 3.0 is moved to the top
+    /// assert_eq!(permutation, vec![1, 0]);
+    /// assert_eq!(l.get(1, 0), &(1.0 / 3.0));
+    /// assert_eq!(u.get(0, 0), &3.0);
+    /// ```
+    pub fn lu(&self) -> Option<(Matrix<f64>, Matrix<f64>, Vec<usize>)> {
+        assert_eq!(self.rows, self.cols, "LU decomposition is only defined for square matrices");
+
+        let factors = LuFactors::new(self, SINGULARITY_TOLERANCE * max_modulus(&self.data))?;
+        let n = self.rows;
+        let mut l = Matrix::zeros(n, n);
+        let mut u = Matrix::zeros(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                let value = factors.lu[i * n + j];
+                match i.cmp(&j) {
+                    std::cmp::Ordering::Greater => l.set(i, j, value),
+                    std::cmp::Ordering::Equal => {
+                        l.set(i, j, 1.0);
+                        u.set(i, j, value);
+                    }
+                    std::cmp::Ordering::Less => u.set(i, j, value),
+                }
+            }
+        }
+        Some((l, u, factors.permutation))
+    }
+
+    /// Solves the linear system `Ax = b`
+    /// 
+    /// The system is solved by LU decomposition with partial pivoting followed by
+    /// forward and back substitution. Returns `None` if the matrix is singular.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square or if `b` does not match the number of rows
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::{Matrix, Vector};
+    /// 
+    /// // 2x + y = 5, x + 3y = 10
+    /// let a = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 3.0]);
+    /// let b = Vector::new(vec![5.0, 10.0]);
+    /// 
+    /// let x = a.solve(&b).unwrap();
+    /// assert!((x.components[0] - 1.0).abs() < 1e-10);
+    /// assert!((x.components[1] - 3.0).abs() < 1e-10);
+    /// ```
+    pub fn solve(&self, b: &Vector) -> Option<Vector> {
+        assert_eq!(self.rows, self.cols, "Matrix must be square to solve a linear system");
+        assert_eq!(self.rows, b.dimension(), "Vector dimension must match matrix rows");
+
+        let factors = LuFactors::new(self, SINGULARITY_TOLERANCE * max_modulus(&self.data))?;
+        Some(Vector::new(factors.solve(&b.components)))
+    }
+
+    /// Solves the linear system `AX = B` for a matrix of right-hand sides
+    /// 
+    /// Runs Gauss-Jordan elimination with partial pivoting on the augmented
+    /// matrix `[A | B]`, reducing `A` to the identity so that the right block
+    /// becomes `X`. All columns of `B` are solved in a single pass. Returns
+    /// `None` if the matrix is singular.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square or if `b` does not have the same number of rows
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 3.0]);
+    /// let b = Matrix::new(2, 2, vec![5.0, 3.0, 10.0, 4.0]);
+    /// 
+    /// let x = a.solve_matrix(&b).unwrap();
+    /// assert!((&a * &x).approx_eq(&b, 1e-10));
+    /// ```
+    pub fn solve_matrix(&self, b: &Matrix<f64>) -> Option<Matrix<f64>> {
+        assert_eq!(self.rows, self.cols, "Matrix must be square to solve a linear system");
+        assert_eq!(self.rows, b.rows, "Right-hand side rows must match matrix rows");
+
+        let n = self.rows;
+        let tolerance = SINGULARITY_TOLERANCE * max_modulus(&self.data);
+        let mut augmented = self.hstack(b);
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&i, &j| augmented.get(i, k).abs().total_cmp(&augmented.get(j, k).abs()))
+                .unwrap();
+            if augmented.get(pivot_row, k).abs() <= tolerance {
+                return None;
+            }
+            augmented.swap_rows(k, pivot_row);
+            augmented.scale_row(k, 1.0 / augmented.get(k, k));
+
+            for i in 0..n {
+                if i != k {
+                    augmented.add_scaled_row(i, k, -augmented.get(i, k));
+                }
+            }
+        }
+        Some(augmented.submatrix(0..n, n..n + b.cols))
+    }
+
+    /// Solves the least-squares problem `min ||Ax - b||`
+    /// 
+    /// Factors `A = QR` with Householder reflections, so the minimizer solves the
+    /// triangular system `Rx = Qᵀb` restricted to the first `cols` rows. This
+    /// avoids forming the normal equations `AᵀAx = Aᵀb`, which square the
+    /// condition number. Returns `None` if the matrix is rank deficient, i.e. if
+    /// a diagonal entry of `R` is smaller than `1e-12` times the largest element
+    /// of the matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix has fewer rows than columns or if `b` does not match
+    /// the number of rows
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::{Matrix, Vector};
+    /// 
+    /// // Fit y = c0 + c1 x through (0, 1), (1, 3), (2, 5)
+    /// let a = Matrix::new(3, 2, vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0]);
+    /// let b = Vector::new(vec![1.0, 3.0, 5.0]);
+    /// 
+    /// let x = a.least_squares(&b).unwrap();
+    /// assert!((x.components[0] - 1.0).abs() < 1e-10);
+    /// assert!((x.components[1] - 2.0).abs() < 1e-10);
+    /// ```
+    pub fn least_squares(&self, b: &Vector) -> Option<Vector> {
+        assert!(self.rows >= self.cols, "Least squares requires at least as many rows as columns");
+        assert_eq!(self.rows, b.dimension(), "Vector dimension must match matrix rows");
+
+        let (m, n) = (self.rows, self.cols);
+        let tolerance = SINGULARITY_TOLERANCE * max_modulus(&self.data);
+        let (q, r) = householder_qr(self);
+        if (0..n).any(|k| r.get(k, k).abs() <= tolerance) {
+            return None;
+        }
+
+        // Back substitution on Rx = Qᵀb
+        let mut x: Vec<f64> = (0..n)
+            .map(|k| (0..m).map(|i| q.get(i, k) * b.components[i]).sum())
+            .collect();
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                x[i] -= r.get(i, j) * x[j];
+            }
+            x[i] /= r.get(i, i);
+        }
+        Some(Vector::new(x))
+    }
+}
+
+// Special implementations for Complex numbers
+impl Matrix<Complex> {
+    /// Creates the 2x2 matrix rotating the plane counterclockwise by `angle`
+    /// 
+    /// The result is `[[cos, -sin], [sin, cos]]` with real entries stored as
+    /// complex numbers.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::{Angle, Complex};
+    /// 
+    /// let rotation = Matrix::rotation(Angle::from_degrees(90.0));
+    /// assert!((*rotation.get(1, 0) - Complex::new(1.0, 0.0)).magnitude() < 1e-10);
+    /// assert!(rotation.is_unitary());
+    /// ```
+    pub fn rotation(angle: Angle) -> Self {
+        let radians = angle.to_radians();
+        let (sin, cos) = radians.sin_cos();
+        Matrix::new(2, 2, vec![
+            Complex::new(cos, 0.0), Complex::new(-sin, 0.0),
+            Complex::new(sin, 0.0), Complex::new(cos, 0.0),
+        ])
+    }
+
+    /// Creates the Pauli X (NOT) gate `[[0, 1], [1, 0]]`
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let x = Matrix::pauli_x();
+    /// assert_eq!(x.get(0, 1), &Complex::new(1.0, 0.0));
+    /// assert!(&x * &x == Matrix::identity(2));
+    /// ```
+    pub fn pauli_x() -> Self {
+        Matrix::new(2, 2, vec![
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+        ])
+    }
+
+    /// Creates the Pauli Y gate `[[0, -i], [i, 0]]`
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let y = Matrix::pauli_y();
+    /// assert_eq!(y.get(0, 1), &Complex::new(0.0, -1.0));
+    /// assert_eq!(y.get(1, 0), &Complex::new(0.0, 1.0));
+    /// ```
+    pub fn pauli_y() -> Self {
+        Matrix::new(2, 2, vec![
+            Complex::new(0.0, 0.0), Complex::new(0.0, -1.0),
+            Complex::new(0.0, 1.0), Complex::new(0.0, 0.0),
+        ])
+    }
+
+    /// Creates the Pauli Z gate `[[1, 0], [0, -1]]`
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let z = Matrix::pauli_z();
+    /// assert_eq!(z.get(1, 1), &Complex::new(-1.0, 0.0));
+    /// ```
+    pub fn pauli_z() -> Self {
+        Matrix::new(2, 2, vec![
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0),
+        ])
+    }
+
+    /// Creates the Hadamard gate `[[1, 1], [1, -1]] / √2`
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let h = Matrix::hadamard();
+    /// assert!(h.is_unitary());
+    /// assert!((&h * &h).approx_eq(&Matrix::identity(2), 1e-10));
+    /// ```
+    pub fn hadamard() -> Self {
+        let scale = std::f64::consts::FRAC_1_SQRT_2;
+        Matrix::new(2, 2, vec![
+            Complex::new(scale, 0.0), Complex::new(scale, 0.0),
+            Complex::new(scale, 0.0), Complex::new(-scale, 0.0),
+        ])
+    }
+
+    /// Creates the phase shift gate `[[1, 0], [0, e^(iφ)]]` for the angle `φ`
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::{Angle, Complex};
+    /// 
+    /// // A half-turn phase shift is the Pauli Z gate
+    /// let s = Matrix::phase(Angle::from_degrees(180.0));
+    /// assert!(s.approx_eq(&Matrix::pauli_z(), 1e-10));
+    /// ```
+    pub fn phase(angle: Angle) -> Self {
+        Matrix::new(2, 2, vec![
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::from_polar(1.0, angle),
+        ])
+    }
+
+    /// Parses a matrix from rows separated by `;` and elements separated by `,`
+    /// 
+    /// Each element is parsed with the `Complex` parser, and whitespace around
+    /// elements is ignored. An empty string yields a 0x0 matrix.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `Err` if an element fails to parse or if the rows do not all
+    /// have the same length
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::from_str_rows("1, 2i; 3-i, 4").unwrap();
+    /// assert_eq!(matrix.rows(), 2);
+    /// assert_eq!(matrix.get(1, 0), &Complex::new(3.0, -1.0));
+    /// 
+    /// assert!(Matrix::from_str_rows("1, 2; 3").is_err());
+    /// ```
+    pub fn from_str_rows(s: &str) -> Result<Self, String> {
+        if s.trim().is_empty() {
+            return Ok(Matrix::new(0, 0, Vec::new()));
+        }
+
+        let rows = s.split(';')
+            .enumerate()
+            .map(|(i, row)| {
+                row.split(',')
+                    .enumerate()
+                    .map(|(j, element)| {
+                        element.parse::<Complex>()
+                            .map_err(|e| format!("Invalid element at ({}, {}): {}", i, j, e))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let cols = rows[0].len();
+        if let Some((i, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != cols) {
+            return Err(format!("Row {} has {} elements, expected {}", i, row.len(), cols));
+        }
+        Ok(Matrix::from_rows(rows))
+    }
+
+    /// Creates a square diagonal matrix with the given diagonal entries
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::from_diagonal(&[Complex::new(2.0, 0.0), Complex::new(0.0, 1.0)]);
+    /// assert_eq!(matrix.get(1, 1), &Complex::new(0.0, 1.0));
+    /// assert_eq!(matrix.get(0, 1), &Complex::new(0.0, 0.0));
+    /// ```
+    pub fn from_diagonal(diag: &[Complex]) -> Self {
+        let mut result = Matrix::zeros(diag.len(), diag.len());
+        for (i, &value) in diag.iter().enumerate() {
+            result.set(i, i, value);
+        }
+        result
+    }
+
+    /// Returns the main diagonal as a complex vector
+    /// 
+    /// For a non-square matrix this has `min(rows, cols)` entries.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(1.0, 0.0), Complex::new(2.0, 0.0),
+    ///     Complex::new(3.0, 0.0), Complex::new(4.0, 0.0),
+    /// ]);
+    /// let diagonal = matrix.diagonal();
+    /// assert_eq!(diagonal[0], Complex::new(1.0, 0.0));
+    /// assert_eq!(diagonal[1], Complex::new(4.0, 0.0));
+    /// ```
+    pub fn diagonal(&self) -> ComplexVector {
+        (0..self.rows.min(self.cols)).map(|i| *self.get(i, i)).collect()
+    }
+
+    /// Returns the row at the given index as a complex vector
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the row index is out of bounds
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(1.0, 2.0), Complex::new(3.0, 4.0),
+    ///     Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)
+    /// ]);
+    /// let row = matrix.row(1);
+    /// assert_eq!(row.components, vec![Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)]);
+    /// ```
+    pub fn row(&self, i: usize) -> ComplexVector {
+        assert!(i < self.rows, "Row index {} out of bounds for matrix with {} rows", i, self.rows);
+        ComplexVector::new(self.data[i * self.cols..(i + 1) * self.cols].to_vec())
+    }
+
+    /// Returns the column at the given index as a complex vector
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the column index is out of bounds
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(1.0, 2.0), Complex::new(3.0, 4.0),
+    ///     Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)
+    /// ]);
+    /// let col = matrix.col(1);
+    /// assert_eq!(col.components, vec![Complex::new(3.0, 4.0), Complex::new(7.0, 8.0)]);
+    /// ```
+    pub fn col(&self, j: usize) -> ComplexVector {
+        assert!(j < self.cols, "Column index {} out of bounds for matrix with {} columns", j, self.cols);
+        ComplexVector::new((0..self.rows).map(|i| *self.get(i, j)).collect())
+    }
+
+    /// Returns a copy with every nonzero row scaled to unit Euclidean norm
+    /// 
+    /// Row norms are computed with `ComplexVector::norm`. Zero rows have no
+    /// direction and are left unchanged.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(3.0, 0.0), Complex::new(0.0, 4.0),
+    ///     Complex::new(0.0, 0.0), Complex::new(0.0, 0.0)
+    /// ]);
+    /// let normalized = matrix.normalize_rows();
+    /// assert_eq!(normalized.get(0, 0), &Complex::new(0.6, 0.0));
+    /// assert_eq!(normalized.get(0, 1), &Complex::new(0.0, 0.8));
+    /// assert_eq!(normalized.row(1), matrix.row(1));
+    /// ```
+    pub fn normalize_rows(&self) -> Matrix<Complex> {
+        let mut result = self.clone();
+        for i in 0..self.rows {
+            let norm = self.row(i).norm();
+            if norm != 0.0 {
+                for j in 0..self.cols {
+                    result.set(i, j, *self.get(i, j) / norm);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns a copy with every nonzero column scaled to unit Euclidean norm
+    /// 
+    /// Column norms are computed with `ComplexVector::norm`. Zero columns have no
+    /// direction and are left unchanged.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(3.0, 0.0), Complex::new(0.0, 0.0),
+    ///     Complex::new(0.0, 4.0), Complex::new(0.0, 0.0)
+    /// ]);
+    /// let normalized = matrix.normalize_columns();
+    /// assert_eq!(normalized.get(0, 0), &Complex::new(0.6, 0.0));
+    /// assert_eq!(normalized.get(1, 0), &Complex::new(0.0, 0.8));
+    /// assert_eq!(normalized.col(1), matrix.col(1));
+    /// ```
+    pub fn normalize_columns(&self) -> Matrix<Complex> {
+        let mut result = self.clone();
+        for j in 0..self.cols {
+            let norm = self.col(j).norm();
+            if norm != 0.0 {
+                for i in 0..self.rows {
+                    result.set(i, j, *self.get(i, j) / norm);
+                }
+            }
+        }
+        result
+    }
+
+    /// Computes the determinant of the matrix
+    /// 
+    /// 2x2 and 3x3 matrices use the closed-form cofactor expansion. Larger
+    /// matrices are reduced by Gaussian elimination with partial pivoting,
+    /// selecting the pivot of largest magnitude in each column.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(1.0, 1.0), Complex::new(2.0, 0.0),
+    ///     Complex::new(0.0, 1.0), Complex::new(3.0, 0.0)
+    /// ]);
+    /// let det = matrix.determinant();
+    /// assert!((det - Complex::new(3.0, 1.0)).magnitude() < 1e-10);
+    /// ```
+    pub fn determinant(&self) -> Complex {
+        determinant(self)
+    }
+
+    /// Computes the inverse of the matrix
+    /// 
+    /// The inverse is computed from an LU decomposition with partial pivoting.
+    /// Returns `None` if the matrix is singular, i.e. if a pivot is smaller than
+    /// `1e-12` times the largest element magnitude of the matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(0.0, 1.0), Complex::new(0.0, 0.0),
+    ///     Complex::new(0.0, 0.0), Complex::new(2.0, 0.0)
+    /// ]);
+    /// let inverse = matrix.inverse().unwrap();
+    /// assert!((*inverse.get(0, 0) - Complex::new(0.0, -1.0)).magnitude() < 1e-10);
+    /// assert!((*inverse.get(1, 1) - Complex::new(0.5, 0.0)).magnitude() < 1e-10);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        inverse(self)
+    }
+
+    /// Computes the eigenvalues of a 2x2 matrix in closed form
+    /// 
+    /// The eigenvalues are the roots of the characteristic polynomial
+    /// `λ² - tr(A)λ + det(A)`, found with the quadratic formula. They are
+    /// returned as `(tr/2 + s, tr/2 - s)` where `s` is the principal square
+    /// root of the discriminant.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not 2x2
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(2.0, 0.0), Complex::new(0.0, 0.0),
+    ///     Complex::new(0.0, 0.0), Complex::new(3.0, 0.0)
+    /// ]);
+    /// let (l1, l2) = matrix.eigenvalues_2x2();
+    /// assert_eq!(l1, Complex::new(3.0, 0.0));
+    /// assert_eq!(l2, Complex::new(2.0, 0.0));
+    /// ```
+    pub fn eigenvalues_2x2(&self) -> (Complex, Complex) {
+        assert!(self.rows == 2 && self.cols == 2, "Closed-form eigenvalues require a 2x2 matrix");
+
+        let (a, b, c, d) = (self.data[0], self.data[1], self.data[2], self.data[3]);
+        let half_trace = (a + d) / 2.0;
+        let discriminant = (half_trace * half_trace - (a * d - b * c)).sqrt();
+        (half_trace + discriminant, half_trace - discriminant)
+    }
+
+    /// Computes the QR decomposition of the matrix using Householder reflections
+    /// 
+    /// Returns `(Q, R)` where `Q` is an `m x m` unitary matrix and `R` is an
+    /// `m x n` upper triangular matrix with `QR` equal to the original matrix.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let a = Matrix::new(2, 2, vec![
+    ///     Complex::new(1.0, 1.0), Complex::new(2.0, 0.0),
+    ///     Complex::new(0.0, 1.0), Complex::new(1.0, -1.0)
+    /// ]);
+    /// let (q, r) = a.qr();
+    /// assert!(q.is_unitary());
+    /// assert_eq!(r.get(1, 0), &Complex::new(0.0, 0.0));
+    /// ```
+    pub fn qr(&self) -> (Matrix<Complex>, Matrix<Complex>) {
+        householder_qr(self)
+    }
+
+    /// Computes all eigenvalues of the matrix using the shifted QR algorithm
+    /// 
+    /// Each iteration factors `A - μI = QR` and replaces `A` with `RQ + μI`, using
+    /// the Wilkinson shift `μ` from the trailing 2x2 block. Once the off-diagonal
+    /// entries of the last active row fall below `tol` times the Frobenius norm,
+    /// that eigenvalue is deflated and the iteration continues on the remaining
+    /// block. The eigenvalues are returned in the order they appear on the final
+    /// diagonal; if `max_iter` iterations are reached first, the current diagonal
+    /// is returned as an approximation.
+    /// 
+    /// The algorithm is intended for Hermitian matrices but also works for
+    /// general complex matrices.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(2.0, 0.0), Complex::new(1.0, 0.0),
+    ///     Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)
+    /// ]);
+    /// let mut eigenvalues: Vec<f64> = matrix.eigenvalues(100, 1e-12).iter().map(|l| l.real).collect();
+    /// eigenvalues.sort_by(|a, b| a.total_cmp(b));
+    /// assert!((eigenvalues[0] - 1.0).abs() < 1e-10);
+    /// assert!((eigenvalues[1] - 3.0).abs() < 1e-10);
+    /// ```
+    pub fn eigenvalues(&self, max_iter: usize, tol: f64) -> Vec<Complex> {
+        assert_eq!(self.rows, self.cols, "Eigenvalues are only defined for square matrices");
+
+        let n = self.rows;
+        let threshold = tol * self.frobenius_norm();
+        let mut h = self.clone();
+        let mut active = n;
+        let mut iterations = 0;
+        while active > 1 && iterations < max_iter {
+            let last = active - 1;
+            let off_diagonal = (0..last)
+                .map(|j| h.get(last, j).magnitude_squared())
+                .sum::<f64>()
+                .sqrt();
+            if off_diagonal <= threshold {
+                active -= 1;
+                continue;
+            }
+
+            let (l1, l2) = h.submatrix(last - 1..active, last - 1..active).eigenvalues_2x2();
+            let corner = *h.get(last, last);
+            let shift = if (l1 - corner).magnitude() <= (l2 - corner).magnitude() { l1 } else { l2 };
+
+            let mut block = h.submatrix(0..active, 0..active);
+            for i in 0..active {
+                block.data[i * active + i] -= shift;
+            }
+            let (q, r) = householder_qr(&block);
+            let next = &r * &q;
+            for i in 0..active {
+                for j in 0..active {
+                    let value = if i == j { *next.get(i, j) + shift } else { *next.get(i, j) };
+                    h.set(i, j, value);
+                }
+            }
+            iterations += 1;
+        }
+        (0..n).map(|i| *h.get(i, i)).collect()
+    }
+
+    /// Computes the Kronecker (tensor) product of this matrix with another matrix
+    /// 
+    /// The result is an `(r1 * r2) x (c1 * c2)` block matrix in which block `(i, j)`
+    /// is `other` scaled by element `(i, j)` of this matrix.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let a = Matrix::new(1, 2, vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+    /// let b = Matrix::new(2, 1, vec![Complex::new(2.0, 0.0), Complex::new(3.0, 0.0)]);
+    /// 
+    /// let product = a.kronecker(&b);
+    /// assert_eq!(product.rows(), 2);
+    /// assert_eq!(product.cols(), 2);
+    /// assert_eq!(product.get(1, 1), &Complex::new(0.0, 3.0));
+    /// ```
+    pub fn kronecker(&self, other: &Matrix<Complex>) -> Matrix<Complex> {
+        let rows = self.rows * other.rows;
+        let cols = self.cols * other.cols;
+        let mut result = Matrix::zeros(rows, cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let scale = *self.get(i, j);
+                for k in 0..other.rows {
+                    for l in 0..other.cols {
+                        result.set(i * other.rows + k, j * other.cols + l, scale * *other.get(k, l));
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Embeds a single-qubit gate so it acts on one wire of a `num_qubits`-qubit register
+    /// 
+    /// Returns `I ⊗ ... ⊗ gate ⊗ ... ⊗ I`, a `2^n x 2^n` matrix, with `gate` in
+    /// position `target`. Qubit 0 is the leftmost factor, i.e. the most
+    /// significant bit of the basis state index.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `gate` is not 2x2 or if `target` is not less than `num_qubits`
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// 
+    /// let x_on_second = Matrix::embed_gate(&Matrix::pauli_x(), 1, 2);
+    /// assert!(x_on_second == Matrix::identity(2).kronecker(&Matrix::pauli_x()));
+    /// ```
+    pub fn embed_gate(gate: &Matrix<Complex>, target: usize, num_qubits: usize) -> Matrix<Complex> {
+        assert!(gate.rows == 2 && gate.cols == 2, "Single-qubit gates must be 2x2 matrices");
+        assert!(target < num_qubits, "Target qubit {} out of bounds for {} qubits", target, num_qubits);
+
+        let before = Matrix::identity(1 << target);
+        let after = Matrix::identity(1 << (num_qubits - target - 1));
+        before.kronecker(gate).kronecker(&after)
+    }
+
+    /// Raises the matrix to a non-negative integer power
+    /// 
+    /// Uses exponentiation by squaring, so only `O(log n)` matrix products are
+    /// needed. `pow(0)` returns the identity matrix.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let i = Matrix::new(1, 1, vec![Complex::new(0.0, 1.0)]);
+    /// assert_eq!(i.pow(2).get(0, 0), &Complex::new(-1.0, 0.0));
+    /// assert_eq!(i.pow(0), Matrix::identity(1));
+    /// ```
+    pub fn pow(&self, n: u32) -> Matrix<Complex> {
+        assert_eq!(self.rows, self.cols, "Matrix power is only defined for square matrices");
+
+        let mut result = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        let mut exponent = n;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        result
+    }
+
+    /// Computes the matrix exponential `e^A`
+    /// 
+    /// Uses scaling and squaring with a degree-6 diagonal Padé approximant: the
+    /// matrix is scaled by `2^-s` until its Frobenius norm is at most `1/2`, the
+    /// approximant `Q(A)^-1 P(A)` is evaluated on the scaled matrix, and the
+    /// result is squared `s` times. This is the solution operator of the linear
+    /// system `dx/dt = Ax`, i.e. `x(t) = e^(At) x(0)`.
+    /// 
+    /// If the Frobenius norm is infinite or NaN, every entry of the result is NaN.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// use std::f64::consts::PI;
+    /// 
+    /// // e^(iπ) = -1
+    /// let matrix = Matrix::new(1, 1, vec![Complex::new(0.0, PI)]);
+    /// let result = matrix.exp();
+    /// assert!((*result.get(0, 0) - Complex::new(-1.0, 0.0)).magnitude() < 1e-12);
+    /// ```
+    pub fn exp(&self) -> Matrix<Complex> {
+        assert_eq!(self.rows, self.cols, "Matrix exponential is only defined for square matrices");
+
+        const PADE_DEGREE: usize = 6;
+        let n = self.rows;
+        let norm = self.frobenius_norm();
+        if !norm.is_finite() {
+            return Matrix::new(n, n, vec![Complex::new(f64::NAN, f64::NAN); n * n]);
+        }
+        let squarings = if norm > 0.5 { (2.0 * norm).log2().ceil() as i32 } else { 0 };
+        let scaled = self.clone() * 0.5f64.powi(squarings);
+
+        // P(A) = sum c_k A^k and Q(A) = sum (-1)^k c_k A^k
+        let mut numerator = Matrix::identity(n);
+        let mut denominator = Matrix::identity(n);
+        let mut power = Matrix::identity(n);
+        let mut coefficient = 1.0;
+        for k in 1..=PADE_DEGREE {
+            coefficient *= (PADE_DEGREE - k + 1) as f64 / (k * (2 * PADE_DEGREE - k + 1)) as f64;
+            power = &scaled * &power;
+            let term = power.clone() * coefficient;
+            numerator = &numerator + &term;
+            denominator = if k % 2 == 0 { &denominator + &term } else { &denominator - &term };
+        }
+
+        // Q(A) is well conditioned once the norm is at most 1/2
+        let denominator = inverse(&denominator).expect("Padé denominator must be invertible");
+        let mut result = &denominator * &numerator;
+        for _ in 0..squarings {
+            result = &result * &result;
+        }
+        result
+    }
+
+    /// Multiplies this matrix by a vector in-place, modifying the matrix
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the number of columns in the matrix does not match the vector dimension
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let mut matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(2.0, 0.0), Complex::new(3.0, 0.0),
+    ///     Complex::new(1.0, 0.0), Complex::new(4.0, 0.0)
+    /// ]);
+    /// 
+    /// let vector = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(2.0, 0.0)]);
+    /// matrix.mul_vector(&vector);
+    /// 
+    /// assert_eq!(matrix.get(0, 0), &Complex::new(8.0, 0.0));  // 2*1 + 3*2 = 8
+    /// assert_eq!(matrix.get(1, 0), &Complex::new(9.0, 0.0));  // 1*1 + 4*2 = 9
+    /// ```
+    pub fn mul_vector(&mut self, vector: &ComplexVector) {
+        let result = &*self * vector;
+
+        // Update matrix dimensions and data
+        self.cols = 1;
+        self.data = result.components;
+    }
+
+    /// Multiplies this matrix by each of several vectors
+    /// 
+    /// Equivalent to computing `self * v` for every `v` in `vs`, but validates
+    /// all dimensions up front and walks the matrix row by row without
+    /// intermediate allocations.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if any vector's dimension does not match the number of columns
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::{Complex, ComplexVector};
+    /// 
+    /// let x = Matrix::pauli_x();
+    /// let states = vec![
+    ///     ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]),
+    ///     ComplexVector::new(vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)]),
+    /// ];
+    /// let flipped = x.mul_vectors(&states);
+    /// assert!(flipped[0] == states[1]);
+    /// assert!(flipped[1] == states[0]);
+    /// ```
+    pub fn mul_vectors(&self, vs: &[ComplexVector]) -> Vec<ComplexVector> {
+        assert!(
+            vs.iter().all(|v| v.dimension() == self.cols),
+            "Matrix columns must match vector dimension"
+        );
+
+        vs.iter()
+            .map(|v| {
+                let mut result = ComplexVector::with_capacity(self.rows);
+                for i in 0..self.rows {
+                    let row = &self.data[i * self.cols..(i + 1) * self.cols];
+                    let mut sum = Complex::new(0.0, 0.0);
+                    for (&a, &b) in row.iter().zip(v.components.iter()) {
+                        sum += a * b;
+                    }
+                    result.push(sum);
+                }
+                result
+            })
+            .collect()
+    }
+
+    /// Computes the conjugate transpose of the matrix
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(1.0, 2.0),
+    ///     Complex::new(3.0, 4.0),
+    ///     Complex::new(5.0, 6.0),
+    ///     Complex::new(7.0, 8.0)
+    /// ]);
+    /// 
+    /// let conjugate_transpose = matrix.conjugate_transpose();
+    /// assert_eq!(conjugate_transpose.get(0, 0), &Complex::new(1.0, -2.0));
+    /// ```
+    pub fn conjugate_transpose(&self) -> Self {
+        let mut result = Matrix::zeros(self.cols, self.rows);
+        for row in 0..self.rows {
+            for col in 0..self.cols {
+                result.set(col, row, self.get(row, col).conjugate());
+            }
+        }
+        result
+    }
+
+    /// Computes the Frobenius norm `sqrt(sum |a_ij|^2)` of the matrix
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(1, 2, vec![Complex::new(3.0, 4.0), Complex::new(0.0, 0.0)]);
+    /// assert_eq!(matrix.frobenius_norm(), 5.0);
+    /// ```
+    pub fn frobenius_norm(&self) -> f64 {
+        self.data.iter().map(|c| c.magnitude_squared()).sum::<f64>().sqrt()
+    }
+
+    /// Computes the Frobenius distance `||self - other||_F` between two matrices
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrices have different dimensions
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let a = Matrix::new(1, 1, vec![Complex::new(1.0, 1.0)]);
+    /// let b = Matrix::new(1, 1, vec![Complex::new(4.0, 5.0)]);
+    /// assert_eq!(a.frobenius_distance(&b), 5.0);
+    /// ```
+    pub fn frobenius_distance(&self, other: &Matrix<Complex>) -> f64 {
+        assert_eq!(self.rows, other.rows, "Matrices must have same number of rows");
+        assert_eq!(self.cols, other.cols, "Matrices must have same number of columns");
+
+        self.data.iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| (*a - *b).magnitude_squared())
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Checks if two matrices are equal within a tolerance
+    /// 
+    /// Returns `false` if the dimensions differ, and otherwise compares each pair
+    /// of elements by `(a - b).magnitude() <= tol`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let a = Matrix::new(1, 1, vec![Complex::new(1.0, 1.0)]);
+    /// let b = Matrix::new(1, 1, vec![Complex::new(1.0, 1.0 + 1e-12)]);
+    /// assert!(a != b);
+    /// assert!(a.approx_eq(&b, 1e-10));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix<Complex>, tol: f64) -> bool {
+        approx_eq(self, other, tol)
+    }
+
+    /// Checks if the matrix is Hermitian within a tolerance
+    /// 
+    /// A matrix is Hermitian if it equals its own conjugate transpose. Non-square
+    /// matrices are never Hermitian.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let hermitian = Matrix::new(2, 2, vec![
+    ///     Complex::new(2.0, 0.0), Complex::new(1.0, -1.0),
+    ///     Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)
+    /// ]);
+    /// assert!(hermitian.is_hermitian(1e-10));
+    /// ```
+    pub fn is_hermitian(&self, tol: f64) -> bool {
+        self.approx_eq(&self.conjugate_transpose(), tol)
+    }
+
+    /// Checks if the matrix is unitary
+    /// 
+    /// A matrix is unitary if its conjugate transpose is its inverse
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let unitary = Matrix::new(2, 2, vec![
+    ///     Complex::new(1.0 / 2.0f64.sqrt(), 0.0),
+    ///     Complex::new(1.0 / 2.0f64.sqrt(), 0.0),
+    ///     Complex::new(1.0 / 2.0f64.sqrt(), 0.0),
+    ///     Complex::new(-1.0 / 2.0f64.sqrt(), 0.0)
+    /// ]);
+    /// 
+    /// assert!(unitary.is_unitary());
+    /// ```
+    pub fn is_unitary(&self) -> bool {
+        if self.rows != self.cols {
+            return false;
+        }
+
+        let product = self * &self.conjugate_transpose();
+        product.approx_eq(&Matrix::identity(self.rows), 1e-10)
+    }
+
+    /// Checks if the matrix is a valid density matrix within a tolerance
+    /// 
+    /// A density matrix is Hermitian, has unit trace and is positive
+    /// semidefinite. Positivity is checked by requiring every eigenvalue to be
+    /// at least `-tol`.
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// // The maximally mixed qubit state I/2
+    /// let mixed = Matrix::new(2, 2, vec![
+    ///     Complex::new(0.5, 0.0), Complex::new(0.0, 0.0),
+    ///     Complex::new(0.0, 0.0), Complex::new(0.5, 0.0)
+    /// ]);
+    /// assert!(mixed.is_density_matrix(1e-10));
+    /// assert!(!Matrix::<Complex>::identity(2).is_density_matrix(1e-10));
+    /// ```
+    pub fn is_density_matrix(&self, tol: f64) -> bool {
+        if !self.is_hermitian(tol) || (self.trace() - Complex::new(1.0, 0.0)).magnitude() > tol {
+            return false;
+        }
+
+        self.eigenvalues(1000, 1e-12).iter().all(|l| l.real >= -tol)
+    }
+
+    /// Returns the matrix divided by its trace, so that the result has unit trace
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the matrix is not square or its trace is zero
+    /// 
+    /// # Example
+    /// ```rust
+    /// use rusticle::linalg::Matrix;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let matrix = Matrix::new(2, 2, vec![
+    ///     Complex::new(3.0, 0.0), Complex::new(1.0, 1.0),
+    ///     Complex::new(1.0, -1.0), Complex::new(1.0, 0.0)
+    /// ]);
+    /// let normalized = matrix.normalize_trace();
+    /// assert_eq!(normalized.trace(), Complex::new(1.0, 0.0));
+    /// assert!(normalized.is_density_matrix(1e-10));
+    /// ```
+    pub fn normalize_trace(&self) -> Matrix<Complex> {
+        let trace = self.trace();
+        assert!(trace != Complex::new(0.0, 0.0), "Cannot normalize a matrix with zero trace");
+
+        self.clone() * (Complex::new(1.0, 0.0) / trace)
+    }
+}
+
+/// Element types with an additive identity
+pub trait Zero {
+    /// Returns the additive identity
+    fn zero() -> Self;
+}
+
+impl<T: Float> Zero for GenericComplex<T> {
+    fn zero() -> Self {
+        GenericComplex::ZERO
+    }
+}
+
+/// Element types with a multiplicative identity
+pub trait One {
+    /// Returns the multiplicative identity
+    fn one() -> Self;
+}
+
+impl<T: Float> One for GenericComplex<T> {
+    fn one() -> Self {
+        GenericComplex::ONE
+    }
+}
+
+// Implements `Zero` and `One` for the primitive numeric types
+macro_rules! impl_zero_one {
+    ($zero:literal, $one:literal; $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_one!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_zero_one!(0.0, 1.0; f32, f64);
+
+/// Element types that support the arithmetic needed by elimination routines
+pub(crate) trait Scalar:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + From<f64>
+{
+    /// Returns the absolute value used to select pivots
+    fn modulus(self) -> f64;
+
+    /// Returns the complex conjugate (the value itself for real numbers)
+    fn conj(self) -> Self;
+}
+
+impl Scalar for f64 {
+    fn modulus(self) -> f64 {
+        self.abs()
+    }
+
+    fn conj(self) -> Self {
+        self
+    }
+}
+
+impl Scalar for Complex {
+    fn modulus(self) -> f64 {
+        self.magnitude()
+    }
+
+    fn conj(self) -> Self {
+        self.conjugate()
+    }
+}
+
+/// Relative tolerance below which a pivot is considered zero
+const SINGULARITY_TOLERANCE: f64 = 1e-12;
+
+/// Returns the largest element modulus, used to scale singularity checks
+fn max_modulus<T: Scalar>(data: &[T]) -> f64 {
+    data.iter().map(|x| x.modulus()).fold(0.0, f64::max)
+}
+
+/// A compact LU factorization `PA = LU` of a square matrix
+/// 
+/// `L` (unit diagonal, not stored) and `U` share a single row-major buffer,
+/// and `permutation[i]` is the row of `A` that ended up in row `i`.
+struct LuFactors<T> {
+    n: usize,
+    lu: Vec<T>,
+    permutation: Vec<usize>,
+    sign: T,
+}
+
+impl<T: Scalar> LuFactors<T> {
+    /// Factorizes a square matrix by Gaussian elimination with partial pivoting
+    /// 
+    /// Returns `None` if any pivot magnitude is at most `tolerance`.
+    fn new(matrix: &Matrix<T>, tolerance: f64) -> Option<Self> {
+        let n = matrix.rows;
+        let mut lu = matrix.data.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = T::from(1.0);
+
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&i, &j| lu[i * n + k].modulus().total_cmp(&lu[j * n + k].modulus()))
+                .unwrap();
+            if lu[pivot_row * n + k].modulus() <= tolerance {
+                return None;
+            }
+            if pivot_row != k {
+                for j in 0..n {
+                    lu.swap(k * n + j, pivot_row * n + j);
+                }
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = lu[k * n + k];
+            for i in (k + 1)..n {
+                let factor = lu[i * n + k] / pivot;
+                lu[i * n + k] = factor;
+                for j in (k + 1)..n {
+                    lu[i * n + j] = lu[i * n + j] - factor * lu[k * n + j];
+                }
+            }
+        }
+        Some(LuFactors { n, lu, permutation, sign })
+    }
+
+    /// Returns the determinant as the signed product of the pivots
+    fn determinant(&self) -> T {
+        (0..self.n).fold(self.sign, |det, i| det * self.lu[i * self.n + i])
+    }
+
+    /// Solves `Ax = b` by forward and back substitution
+    fn solve(&self, b: &[T]) -> Vec<T> {
+        let n = self.n;
+        let mut x: Vec<T> = self.permutation.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] = x[i] - self.lu[i * n + j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                x[i] = x[i] - self.lu[i * n + j] * x[j];
+            }
+            x[i] = x[i] / self.lu[i * n + i];
+        }
+        x
+    }
+}
+
+/// Compares dimensions, then each pair of elements by `|a - b| <= tol`
+fn approx_eq<T: Scalar>(a: &Matrix<T>, b: &Matrix<T>, tol: f64) -> bool {
+    a.rows == b.rows
+        && a.cols == b.cols
+        && a.data.iter().zip(b.data.iter()).all(|(&x, &y)| (x - y).modulus() <= tol)
+}
+
+/// Computes the determinant of a 2x2 or 3x3 matrix by cofactor expansion
+fn closed_form_determinant<T: Scalar>(n: usize, a: &[T]) -> T {
+    if n == 2 {
+        a[0] * a[3] - a[1] * a[2]
+    } else {
+        a[0] * (a[4] * a[8] - a[5] * a[7])
+            - a[1] * (a[3] * a[8] - a[5] * a[6])
+            + a[2] * (a[3] * a[7] - a[4] * a[6])
+    }
+}
+
+/// Computes the determinant, in closed form for 2x2 and 3x3 matrices and from
+/// an LU factorization otherwise
+fn determinant<T: Scalar>(matrix: &Matrix<T>) -> T {
+    assert_eq!(matrix.rows, matrix.cols, "Determinant is only defined for square matrices");
+
+    if matches!(matrix.rows, 2 | 3) {
+        return closed_form_determinant(matrix.rows, &matrix.data);
+    }
+    match LuFactors::new(matrix, 0.0) {
+        Some(factors) => factors.determinant(),
+        None => T::from(0.0),
+    }
+}
+
+/// Computes the inverse by solving for each column of the identity
+/// 
+/// There is no closed-form path for small matrices: singularity is decided by
+/// the LU pivots, so every size uses the same criterion as `solve`, and the
+/// adjugate would only add work on top of the factorization.
+fn inverse<T: Scalar>(matrix: &Matrix<T>) -> Option<Matrix<T>> {
+    assert_eq!(matrix.rows, matrix.cols, "Inverse is only defined for square matrices");
+
+    let n = matrix.rows;
+    let factors = LuFactors::new(matrix, SINGULARITY_TOLERANCE * max_modulus(&matrix.data))?;
+    let mut result = vec![T::from(0.0); n * n];
+    let mut unit = vec![T::from(0.0); n];
+    for j in 0..n {
+        unit[j] = T::from(1.0);
+        let column = factors.solve(&unit);
+        for i in 0..n {
+            result[i * n + j] = column[i];
+        }
+        unit[j] = T::from(0.0);
+    }
+    Some(Matrix::new(n, n, result))
+}
+
+/// Computes `A = QR` using Householder reflections
+/// 
+/// `Q` is an `m x m` unitary matrix accumulated from the reflections and `R` is
+/// an `m x n` upper triangular matrix.
+fn householder_qr<T: Scalar>(matrix: &Matrix<T>) -> (Matrix<T>, Matrix<T>) {
+    let (m, n) = (matrix.rows, matrix.cols);
+    let mut r = matrix.data.clone();
+    let mut q = vec![T::from(0.0); m * m];
+    for i in 0..m {
+        q[i * m + i] = T::from(1.0);
+    }
+
+    for k in 0..n.min(m.saturating_sub(1)) {
+        let norm = (k..m).map(|i| r[i * n + k].modulus().powi(2)).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+
+        // Reflect x onto alpha * e1, choosing the phase of alpha to avoid cancellation
+        let x0 = r[k * n + k];
+        let phase = if x0.modulus() == 0.0 { T::from(1.0) } else { x0 / T::from(x0.modulus()) };
+        let alpha = -(phase * T::from(norm));
+        let mut v: Vec<T> = (k..m).map(|i| r[i * n + k]).collect();
+        v[0] = v[0] - alpha;
+        let v_norm = v.iter().map(|x| x.modulus().powi(2)).sum::<f64>().sqrt();
+        if v_norm == 0.0 {
+            continue;
+        }
+        for x in v.iter_mut() {
+            *x = *x / T::from(v_norm);
+        }
+
+        // R = H R with H = I - 2 v v*
+        for j in 0..n {
+            let s = (0..v.len()).fold(T::from(0.0), |acc, l| acc + v[l].conj() * r[(k + l) * n + j]);
+            for l in 0..v.len() {
+                r[(k + l) * n + j] = r[(k + l) * n + j] - T::from(2.0) * v[l] * s;
+            }
+        }
+
+        // Q = Q H
+        for i in 0..m {
+            let s = (0..v.len()).fold(T::from(0.0), |acc, l| acc + q[i * m + k + l] * v[l]);
+            for l in 0..v.len() {
+                q[i * m + k + l] = q[i * m + k + l] - T::from(2.0) * s * v[l].conj();
+            }
+        }
+
+        for i in (k + 1)..m {
+            r[i * n + k] = T::from(0.0);
+        }
+    }
+    (Matrix::new(m, m, q), Matrix::new(m, n, r))
+}
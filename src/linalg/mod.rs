@@ -4,6 +4,8 @@
 //! linear algebra operations.
 
 pub mod matrix;
+pub mod vector;
 
 // Re-exports 
-pub use matrix::Matrix;
\ No newline at end of file
+pub use matrix::Matrix;
+pub use vector::Vector;
\ No newline at end of file
@@ -0,0 +1,265 @@
+//! Real vectors and their operations
+
+use std::ops::{Add, Sub, Mul, Neg};
+use std::fmt;
+use crate::complex::{Complex, ComplexVector};
+
+/// A vector of real numbers
+/// 
+/// This type mirrors the API of `ComplexVector` for real-valued data,
+/// including basic arithmetic, dot product, and norm calculations.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use rusticle::linalg::Vector;
+/// 
+/// // Create vectors
+/// let v1 = Vector::new(vec![1.0, 2.0, 3.0]);
+/// let v2 = Vector::new(vec![4.0, 5.0, 6.0]);
+/// 
+/// // Vector addition
+/// let sum = v1.clone() + v2.clone();
+/// 
+/// // Scalar multiplication
+/// let scaled = v1.clone() * 2.0;
+/// 
+/// // Dot product
+/// let dot = v1.dot(&v2);
+/// 
+/// // Vector norm
+/// let norm = v1.norm();
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct Vector {
+    /// The components of the vector
+    pub components: Vec<f64>,
+}
+
+impl Vector {
+    /// Creates a new real vector from a vector of floats
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::linalg::Vector;
+    /// 
+    /// let v = Vector::new(vec![1.0, 2.0]);
+    /// assert_eq!(v.dimension(), 2);
+    /// ```
+    pub fn new(components: Vec<f64>) -> Self {
+        Vector { components }
+    }
+
+    /// Creates a zero vector of the specified dimension
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::linalg::Vector;
+    /// 
+    /// let v = Vector::zeros(3);
+    /// assert_eq!(v.dimension(), 3);
+    /// assert!(v.is_zero());
+    /// ```
+    pub fn zeros(dimension: usize) -> Self {
+        Vector {
+            components: vec![0.0; dimension],
+        }
+    }
+
+    /// Returns the dimension of the vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::linalg::Vector;
+    /// 
+    /// let v = Vector::new(vec![1.0, 2.0]);
+    /// assert_eq!(v.dimension(), 2);
+    /// ```
+    pub fn dimension(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Checks if the vector is a zero vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::linalg::Vector;
+    /// 
+    /// assert!(Vector::zeros(2).is_zero());
+    /// assert!(!Vector::new(vec![1.0, 0.0]).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.components.iter().all(|&x| x == 0.0)
+    }
+
+    /// Returns the Euclidean norm (length) of the vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::linalg::Vector;
+    /// 
+    /// let v = Vector::new(vec![3.0, 4.0]);
+    /// assert_eq!(v.norm(), 5.0);
+    /// ```
+    pub fn norm(&self) -> f64 {
+        self.norm_squared().sqrt()
+    }
+
+    /// Returns the squared Euclidean norm of the vector
+    /// 
+    /// This is more efficient than computing the norm and then squaring it.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::linalg::Vector;
+    /// 
+    /// let v = Vector::new(vec![3.0, 4.0]);
+    /// assert_eq!(v.norm_squared(), 25.0);
+    /// ```
+    pub fn norm_squared(&self) -> f64 {
+        self.components.iter().map(|x| x * x).sum::<f64>()
+    }
+
+    /// Returns the dot product of this vector with another vector
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::linalg::Vector;
+    /// 
+    /// let v1 = Vector::new(vec![1.0, 2.0, 3.0]);
+    /// let v2 = Vector::new(vec![4.0, 5.0, 6.0]);
+    /// assert_eq!(v1.dot(&v2), 32.0);
+    /// ```
+    pub fn dot(&self, other: &Vector) -> f64 {
+        assert_eq!(self.dimension(), other.dimension(), "Vectors must have the same dimension for dot product");
+
+        self.components.iter()
+            .zip(other.components.iter())
+            .map(|(a, b)| a * b)
+            .sum::<f64>()
+    }
+
+    /// Returns the normalized version of this vector (unit vector)
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::linalg::Vector;
+    /// 
+    /// let v = Vector::new(vec![3.0, 4.0]);
+    /// let normalized = v.normalize();
+    /// assert!((normalized.norm() - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        assert!(norm != 0.0, "Cannot normalize a zero vector");
+
+        let mut normalized = self.clone();
+        for i in 0..self.dimension() {
+            normalized.components[i] /= norm;
+        }
+        normalized
+    }
+
+    /// Converts this vector to a complex vector with zero imaginary parts
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use rusticle::linalg::Vector;
+    /// use rusticle::complex::Complex;
+    /// 
+    /// let v = Vector::new(vec![1.0, 2.0]);
+    /// let complex = v.to_complex();
+    /// assert_eq!(complex.components[1], Complex::new(2.0, 0.0));
+    /// ```
+    pub fn to_complex(&self) -> ComplexVector {
+        ComplexVector::new(self.components.iter().map(|&x| Complex::from(x)).collect())
+    }
+}
+
+/// Custom Debug implementation for Vector
+impl fmt::Debug for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, component) in self.components.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{:?}", component)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Implement vector addition
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        assert_eq!(self.dimension(), other.dimension(), "Vectors must have the same dimension for addition");
+
+        let mut result = Vec::with_capacity(self.dimension());
+        for i in 0..self.dimension() {
+            result.push(self.components[i] + other.components[i]);
+        }
+        Vector::new(result)
+    }
+}
+
+/// Implement vector subtraction
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        assert_eq!(self.dimension(), other.dimension(), "Vectors must have the same dimension for subtraction");
+
+        let mut result = Vec::with_capacity(self.dimension());
+        for i in 0..self.dimension() {
+            result.push(self.components[i] - other.components[i]);
+        }
+        Vector::new(result)
+    }
+}
+
+/// Implement scalar multiplication (vector * scalar)
+impl Mul<f64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, scalar: f64) -> Vector {
+        let mut result = Vec::with_capacity(self.dimension());
+        for i in 0..self.dimension() {
+            result.push(self.components[i] * scalar);
+        }
+        Vector::new(result)
+    }
+}
+
+/// Implement scalar multiplication (scalar * vector)
+impl Mul<Vector> for f64 {
+    type Output = Vector;
+
+    fn mul(self, vector: Vector) -> Vector {
+        vector * self
+    }
+}
+
+/// Implement vector negation
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        let mut result = Vec::with_capacity(self.dimension());
+        for i in 0..self.dimension() {
+            result.push(-self.components[i]);
+        }
+        Vector::new(result)
+    }
+}
@@ -46,4 +46,50 @@ mod angle_tests {
         let in_deg = rad.as_degrees();
         assert_eq!(in_deg.to_degrees(), 180.0);
     }
-} 
\ No newline at end of file
+
+    /// Tests conversion to and from gradians and turns.
+    #[test]
+    fn test_angle_gradians_and_turns() {
+        let grad = Angle::from_gradians(100.0);
+        assert!((grad.to_degrees() - 90.0).abs() < 1e-10);
+
+        let turns = Angle::from_turns(0.25);
+        assert!((turns.to_degrees() - 90.0).abs() < 1e-10);
+
+        let angle = Angle::from_degrees(123.4);
+        assert!((Angle::from_gradians(angle.to_gradians()).to_degrees() - 123.4).abs() < 1e-10);
+        assert!((Angle::from_turns(angle.to_turns()).to_degrees() - 123.4).abs() < 1e-10);
+    }
+
+    /// Tests equality and ordering across units.
+    #[test]
+    fn test_angle_comparison() {
+        assert_eq!(Angle::from_degrees(180.0), Angle::from_radians(PI));
+        assert!(Angle::from_degrees(360.0).eq_approx(&Angle::from_radians(0.0), 1e-10));
+        assert!(!Angle::from_degrees(10.0).eq_approx(&Angle::from_degrees(20.0), 1e-10));
+
+        let mut angles = [
+            Angle::from_turns(0.5),
+            Angle::from_degrees(45.0),
+            Angle::from_radians(PI / 2.0),
+            Angle::from_gradians(10.0),
+        ];
+        angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let degrees: Vec<f64> = angles.iter().map(|a| a.to_degrees()).collect();
+        for pair in degrees.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
+        assert!((degrees[0] - 9.0).abs() < 1e-10);
+        assert!((degrees[3] - 180.0).abs() < 1e-10);
+    }
+
+    /// Tests the common angle constants.
+    #[test]
+    fn test_angle_constants() {
+        assert_eq!(Angle::ZERO.to_degrees(), 0.0);
+        assert!((Angle::RIGHT.to_radians() - PI / 2.0).abs() < 1e-10);
+        assert!((Angle::STRAIGHT.to_radians() - PI).abs() < 1e-10);
+        assert!(Angle::FULL.eq_approx(&Angle::ZERO, 1e-10));
+    }
+}
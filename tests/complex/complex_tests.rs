@@ -1,5 +1,5 @@
 use rusticle::Angle;
-use rusticle::complex::Complex;
+use rusticle::complex::{gaussian_gcd, solve_quadratic, Complex, Complex32};
 
 /// Test suite for the Complex type.
 /// 
@@ -98,4 +98,431 @@ mod complex_tests {
         let z5 = Complex::new(0.0, 0.0);
         assert_eq!(format!("{:?}", z5), "0");
     }
-} 
\ No newline at end of file
+
+    /// Tests the principal square root.
+    #[test]
+    fn test_complex_sqrt() {
+        assert_eq!(Complex::new(4.0, 0.0).sqrt(), Complex::new(2.0, 0.0));
+        assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+        assert_eq!(Complex::new(3.0, -4.0).sqrt(), Complex::new(2.0, -1.0));
+
+        let z = Complex::new(-1.5, 2.5);
+        let root = z.sqrt();
+        assert!(root.real >= 0.0);
+        assert!((root * root - z).magnitude() < 1e-10);
+    }
+
+    /// Tests serde serialization as a `{"real", "imag"}` object.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_complex_serde_round_trip() {
+        let z = Complex::new(3.5, -4.25);
+        let json = serde_json::to_string(&z).unwrap();
+        assert_eq!(json, r#"{"real":3.5,"imag":-4.25}"#);
+
+        let parsed: Complex = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, z);
+
+        let values = vec![Complex::new(1.0, 0.0), Complex::new(0.0, -1.0)];
+        let json = serde_json::to_string(&values).unwrap();
+        let parsed: Vec<Complex> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, values);
+    }
+
+    /// Tests round-trip conversion through `num_complex::Complex64`.
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_complex_num_complex_round_trip() {
+        let z = Complex::new(0.1, -2.0 / 3.0);
+        let converted: num_complex::Complex64 = z.into();
+        assert_eq!(converted.re, z.real);
+        assert_eq!(converted.im, z.imag);
+
+        let back = Complex::from(converted);
+        assert_eq!(back, z);
+    }
+
+    /// Tests that equal values produce equal hash keys.
+    #[test]
+    fn test_complex_to_bits_key() {
+        let z = Complex::new(1.5, -2.25);
+        assert_eq!(z.to_bits_key(), Complex::new(1.5, -2.25).to_bits_key());
+        assert_ne!(z.to_bits_key(), Complex::new(-2.25, 1.5).to_bits_key());
+
+        // Signed zeros compare equal, so their keys must match
+        assert_eq!(Complex::new(0.0, 0.0), Complex::new(-0.0, -0.0));
+        assert_eq!(Complex::new(0.0, 0.0).to_bits_key(), Complex::new(-0.0, -0.0).to_bits_key());
+    }
+
+    /// Tests that `to_polar` round-trips values built with `from_polar`.
+    #[test]
+    fn test_complex_to_polar_round_trip() {
+        let cases = [(1.0, 0.0), (2.5, 0.3), (0.75, 2.5), (4.0, -1.2), (3.0, std::f64::consts::FRAC_PI_2), (1.0, -3.0)];
+        for (r, theta) in cases {
+            let z = Complex::from_polar(r, Angle::from_radians(theta));
+            let (magnitude, angle) = z.to_polar();
+            assert!((magnitude - r).abs() < 1e-10);
+            assert!((angle.to_radians() - theta).abs() < 1e-10);
+        }
+    }
+
+    /// Tests single-precision complex numbers.
+    #[test]
+    fn test_complex32_operations() {
+        let z = Complex32::new(3.0, 4.0);
+        let w = Complex32::new(1.0, -2.0);
+
+        assert_eq!(z + w, Complex32::new(4.0, 2.0));
+        assert_eq!(z * w, Complex32::new(11.0, -2.0));
+        assert_eq!(z.magnitude(), 5.0f32);
+        assert_eq!(z.conjugate(), Complex32::new(3.0, -4.0));
+        assert_eq!(Complex32::I * Complex32::I, Complex32::new(-1.0, 0.0));
+        assert_eq!(Complex32::new(3.0, -4.0).sqrt(), Complex32::new(2.0, -1.0));
+
+        let quotient = z / w;
+        assert!((quotient * w - z).magnitude() < 1e-5);
+
+        let parsed: Complex32 = "1.5-2.5i".parse().unwrap();
+        assert_eq!(parsed, Complex32::new(1.5, -2.5));
+        assert_eq!(format!("{:?}", parsed), "1.5-2.5i");
+
+        let polar = Complex32::from_polar(2.0, Angle::from_degrees(90.0));
+        assert!((polar - Complex32::new(0.0, 2.0)).magnitude() < 1e-6);
+        assert_eq!(z.to_bits_key(), Complex::new(3.0, 4.0).to_bits_key());
+    }
+
+    /// Tests choosing the argument branch closest to a reference phase.
+    #[test]
+    fn test_complex_argument_near() {
+        use std::f64::consts::PI;
+
+        // Just below the negative real axis the principal argument is near -π
+        let z = Complex::new(-1.0, -1e-9);
+        assert!((z.argument() + PI).abs() < 1e-6);
+        assert!((z.argument_near(PI) - PI).abs() < 1e-6);
+
+        // A reference on the principal branch leaves the argument unchanged
+        let w = Complex::new(1.0, 1.0);
+        assert_eq!(w.argument_near(0.0), w.argument());
+
+        // Several turns away, the result is shifted by whole turns
+        let shifted = w.argument_near(6.0 * PI);
+        assert!((shifted - (PI / 4.0 + 6.0 * PI)).abs() < 1e-10);
+    }
+
+    /// Tests rounding both parts of a complex number.
+    #[test]
+    fn test_complex_rounding() {
+        assert_eq!(Complex::new(2.0000001, -2.999999).round(), Complex::new(2.0, -3.0));
+        assert_eq!(Complex::new(0.5, -0.5).round(), Complex::new(1.0, -1.0));
+        assert_eq!(Complex::new(1.9, -1.1).floor(), Complex::new(1.0, -2.0));
+        assert_eq!(Complex::new(1.1, -1.9).ceil(), Complex::new(2.0, -1.0));
+        assert_eq!(Complex32::new(1.5, -0.25).floor(), Complex32::new(1.0, -1.0));
+    }
+
+    /// Tests clearing tiny parts of a complex number.
+    #[test]
+    fn test_complex_chop() {
+        assert_eq!(Complex::new(1.0, 1e-16).chop(1e-12), Complex::new(1.0, 0.0));
+        assert_eq!(Complex::new(-1e-13, -2.0).chop(1e-12), Complex::new(0.0, -2.0));
+        assert_eq!(Complex::new(1e-13, 1e-13).chop(1e-14), Complex::new(1e-13, 1e-13));
+    }
+
+    /// Tests sorting complex numbers by magnitude, then by argument.
+    #[test]
+    fn test_complex_sort_comparators() {
+        let mut values = [
+            Complex::new(f64::NAN, 0.0),
+            Complex::new(0.0, 2.0),
+            Complex::new(-1.0, 0.0),
+            Complex::new(2.0, 0.0),
+            Complex::new(1.0, 0.0),
+        ];
+        values.sort_by(|a, b| a.cmp_by_magnitude(b).then_with(|| a.cmp_by_argument(b)));
+
+        assert_eq!(values[0], Complex::new(1.0, 0.0));
+        assert_eq!(values[1], Complex::new(-1.0, 0.0));
+        assert_eq!(values[2], Complex::new(2.0, 0.0));
+        assert_eq!(values[3], Complex::new(0.0, 2.0));
+        assert!(values[4].real.is_nan());
+
+        let mut by_argument = [Complex::new(0.0, -1.0), Complex::new(f64::NAN, 1.0), Complex::new(1.0, 1.0)];
+        by_argument.sort_by(|a, b| a.cmp_by_argument(b));
+        assert_eq!(by_argument[0], Complex::new(0.0, -1.0));
+        assert_eq!(by_argument[1], Complex::new(1.0, 1.0));
+        assert!(by_argument[2].real.is_nan());
+    }
+
+    /// Tests formatting with a custom imaginary symbol and precision.
+    #[test]
+    fn test_complex_format_with() {
+        assert_eq!(Complex::new(1.5, -2.0).format_with('j', 1), "1.5-2.0j");
+        assert_eq!(Complex::new(0.0, 1.0).format_with('i', 0), "0+1i");
+        assert_eq!(Complex::new(-1.236, 4.568).format_with('𝑖', 2), "-1.24+4.57𝑖");
+        assert_eq!(Complex::new(1.0, -0.0).format_with('j', 1), "1.0+0.0j");
+    }
+
+    /// Tests natural and base-specific logarithms.
+    #[test]
+    fn test_complex_logarithms() {
+        use std::f64::consts::{FRAC_PI_2, LN_2, LN_10};
+
+        let log = Complex::from(100.0).log10();
+        assert!((log.real - 2.0).abs() < 1e-12);
+        assert_eq!(log.imag, 0.0);
+
+        let z = Complex::new(0.0, 8.0);
+        assert!((z.log2() - Complex::new(3.0, FRAC_PI_2 / LN_2)).magnitude() < 1e-12);
+        assert!((z.log10() - Complex::new(8.0f64.log10(), FRAC_PI_2 / LN_10)).magnitude() < 1e-12);
+
+        // ln is the inverse of exp on the principal branch
+        let w = Complex::new(0.5, -1.25);
+        assert!((w.exp().ln() - w).magnitude() < 1e-12);
+
+        for base in [1.0, 0.0, -2.0, f64::NAN] {
+            let undefined = z.log(base);
+            assert!(undefined.real.is_nan() && undefined.imag.is_nan());
+        }
+    }
+
+    /// Tests that every string produced by Debug parses back to the same value.
+    #[test]
+    fn test_complex_debug_round_trip() {
+        let values = [
+            0.0, -0.0, 1.0, -1.0, 2.5, -2.5, 0.1, -0.3,
+            1e-20, -1e-20, 1e300, -1e300, 123456.789,
+            f64::MIN_POSITIVE, f64::MAX, f64::MIN, 5e-324,
+        ];
+
+        for &real in &values {
+            for &imag in &values {
+                let z = Complex::new(real, imag);
+                let text = format!("{:?}", z);
+                let parsed: Complex = text.parse().unwrap();
+                assert_eq!(parsed, z, "{} did not round-trip", text);
+            }
+        }
+
+        // Accepted shorthand forms
+        assert_eq!("-i".parse::<Complex>().unwrap(), Complex::new(0.0, -1.0));
+        assert_eq!("+2i".parse::<Complex>().unwrap(), Complex::new(0.0, 2.0));
+        assert_eq!("3-i".parse::<Complex>().unwrap(), Complex::new(3.0, -1.0));
+        assert_eq!("1e5-2.5e-3i".parse::<Complex>().unwrap(), Complex::new(1e5, -2.5e-3));
+    }
+
+    /// Tests parsing of the `inf` and `nan` special tokens in either part.
+    #[test]
+    fn test_complex_parse_special_values() {
+        let z: Complex = "inf+2i".parse().unwrap();
+        assert!(z.real.is_infinite() && z.real > 0.0);
+        assert_eq!(z.imag, 2.0);
+
+        let z: Complex = "-inf-infi".parse().unwrap();
+        assert_eq!(z, Complex::new(f64::NEG_INFINITY, f64::NEG_INFINITY));
+
+        let z: Complex = "inf+nan i".parse().unwrap();
+        assert!(z.real.is_infinite());
+        assert!(z.imag.is_nan());
+
+        let z: Complex = "nan".parse().unwrap();
+        assert!(z.real.is_nan());
+        assert_eq!(z.imag, 0.0);
+
+        let z: Complex = "1-infi".parse().unwrap();
+        assert_eq!(z, Complex::new(1.0, f64::NEG_INFINITY));
+
+        // Debug output of special values parses back as well
+        let z: Complex = format!("{:?}", Complex::new(1.0, f64::NAN)).parse().unwrap();
+        assert_eq!(z.real, 1.0);
+        assert!(z.imag.is_nan());
+        let w = Complex::new(f64::INFINITY, f64::INFINITY);
+        assert_eq!(format!("{:?}", w).parse::<Complex>().unwrap(), w);
+    }
+
+    /// Tests that the Gaussian remainder reduces the norm below the divisor's.
+    #[test]
+    fn test_complex_gaussian_rem() {
+        let pairs = [
+            (Complex::new(7.0, 3.0), Complex::new(2.0, -1.0)),
+            (Complex::new(27.0, -23.0), Complex::new(8.0, 1.0)),
+            (Complex::new(-11.0, 5.0), Complex::new(3.0, 3.0)),
+            (Complex::new(100.0, 0.0), Complex::new(0.0, 7.0)),
+            (Complex::new(5.0, 0.0), Complex::new(2.0, 1.0)),
+        ];
+
+        for (a, b) in pairs {
+            let r = a.gaussian_rem(b);
+            assert!(r.norm_squared() <= b.norm_squared() / 2.0);
+            assert_eq!(r, r.round());
+
+            // a - r is an exact Gaussian multiple of b
+            let quotient = (a - r) / b;
+            assert!((quotient - quotient.round()).magnitude() < 1e-12);
+        }
+
+        // Exact multiples leave no remainder
+        let b = Complex::new(2.0, 1.0);
+        assert_eq!((b * Complex::new(3.0, -4.0)).gaussian_rem(b), Complex::new(0.0, 0.0));
+    }
+
+    /// Tests that the Gaussian remainder by zero panics.
+    #[test]
+    #[should_panic(expected = "Cannot take the Gaussian remainder by zero")]
+    fn test_complex_gaussian_rem_by_zero() {
+        let _remainder = Complex::new(1.0, 1.0).gaussian_rem(Complex::new(0.0, 0.0)); // Should panic
+    }
+
+    /// Tests the Euclidean algorithm for Gaussian integers.
+    #[test]
+    fn test_gaussian_gcd() {
+        let units = [
+            Complex::new(1.0, 0.0),
+            Complex::new(-1.0, 0.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(0.0, -1.0),
+        ];
+        let is_associate = |g: Complex, expected: Complex| units.iter().any(|&u| g * u == expected);
+
+        // gcd(5, 2+i) is an associate of 2+i
+        let g = gaussian_gcd(Complex::new(5.0, 0.0), Complex::new(2.0, 1.0));
+        assert!(is_associate(g, Complex::new(2.0, 1.0)));
+
+        // Common factor 1+2i of (1+2i)(3-i) and (1+2i)(2+3i)
+        let factor = Complex::new(1.0, 2.0);
+        let a = factor * Complex::new(3.0, -1.0);
+        let b = factor * Complex::new(2.0, 3.0);
+        assert!(is_associate(gaussian_gcd(a, b), factor));
+
+        // Coprime inputs have a unit gcd
+        let g = gaussian_gcd(Complex::new(3.0, 0.0), Complex::new(2.0, 1.0));
+        assert_eq!(g.norm_squared(), 1.0);
+
+        // gcd with zero is the other argument
+        let z = Complex::new(4.0, -6.0);
+        assert_eq!(gaussian_gcd(z, Complex::new(0.0, 0.0)), z);
+        assert!(is_associate(gaussian_gcd(Complex::new(0.0, 0.0), z), z));
+    }
+
+    /// Tests that the Gaussian gcd rejects NaN inputs instead of looping forever.
+    #[test]
+    #[should_panic(expected = "Gaussian gcd requires finite inputs")]
+    fn test_gaussian_gcd_nan() {
+        let _g = gaussian_gcd(Complex::new(f64::NAN, 0.0), Complex::new(2.0, 1.0)); // Should panic
+    }
+
+    /// Tests that the Gaussian gcd rejects infinite inputs instead of looping forever.
+    #[test]
+    #[should_panic(expected = "Gaussian gcd requires finite inputs")]
+    fn test_gaussian_gcd_infinite() {
+        let _g = gaussian_gcd(Complex::new(5.0, 0.0), Complex::new(0.0, f64::INFINITY)); // Should panic
+    }
+
+    /// Tests quadratic roots, including complex roots of real-coefficient equations.
+    #[test]
+    fn test_solve_quadratic() {
+        let real = |x: f64| Complex::new(x, 0.0);
+        let sorted = |(z1, z2): (Complex, Complex)| {
+            if z1.imag <= z2.imag { (z1, z2) } else { (z2, z1) }
+        };
+
+        // z² + 2z + 5 = 0 has roots -1 ± 2i
+        let (z1, z2) = sorted(solve_quadratic(real(1.0), real(2.0), real(5.0)));
+        assert!((z1 - Complex::new(-1.0, -2.0)).magnitude() < 1e-12);
+        assert!((z2 - Complex::new(-1.0, 2.0)).magnitude() < 1e-12);
+
+        // 2z² - 4z + 4 = 0 has roots 1 ± i
+        let (z1, z2) = sorted(solve_quadratic(real(2.0), real(-4.0), real(4.0)));
+        assert!((z1 - Complex::new(1.0, -1.0)).magnitude() < 1e-12);
+        assert!((z2 - Complex::new(1.0, 1.0)).magnitude() < 1e-12);
+
+        // Real roots with very different magnitudes are both accurate
+        let (z1, z2) = solve_quadratic(real(1.0), real(-1e8), real(1.0));
+        let (small, large) = if z1.magnitude() < z2.magnitude() { (z1, z2) } else { (z2, z1) };
+        assert!((small.real - 1e-8).abs() < 1e-20);
+        assert!((large.real - 1e8).abs() < 1e-4);
+
+        // Complex coefficients satisfy the equation
+        let (a, b, c) = (Complex::new(1.0, -1.0), Complex::new(2.0, 3.0), Complex::new(-4.0, 0.5));
+        let (z1, z2) = solve_quadratic(a, b, c);
+        for z in [z1, z2] {
+            assert!((a * z * z + b * z + c).magnitude() < 1e-12);
+        }
+
+        // Repeated and zero roots
+        assert_eq!(solve_quadratic(real(1.0), real(0.0), real(0.0)), (real(0.0), real(0.0)));
+        let (z1, z2) = solve_quadratic(real(1.0), real(-2.0), real(1.0));
+        assert!((z1 - real(1.0)).magnitude() < 1e-12 && (z2 - real(1.0)).magnitude() < 1e-12);
+
+        // a == 0 degenerates to the linear root -c/b
+        assert_eq!(solve_quadratic(real(0.0), real(2.0), real(-3.0)), (real(1.5), real(1.5)));
+    }
+
+    /// Tests that mul_add avoids the intermediate rounding of the naive expression.
+    #[test]
+    fn test_complex_mul_add() {
+        let z = Complex::new(1.0, 2.0);
+        let a = Complex::new(-0.5, 4.0);
+        let b = Complex::new(3.0, -1.0);
+        assert_eq!(z.mul_add(a, b), z * a + b);
+
+        // (1 + ε)(1 - ε) - 1 = -ε² is lost entirely when the product is rounded
+        let eps = 2.0f64.powi(-30);
+        let z = Complex::new(1.0 + eps, 0.0);
+        let a = Complex::new(1.0 - eps, 0.0);
+        let b = Complex::new(-1.0, 0.0);
+        assert_eq!((z * a + b).real, 0.0);
+        assert_eq!(z.mul_add(a, b).real, -eps * eps);
+
+        // The same cancellation in the imaginary part
+        let z = Complex::new(0.0, 1.0 + eps);
+        let a = Complex::new(1.0 - eps, 0.0);
+        let b = Complex::new(0.0, -1.0);
+        assert_eq!((z * a + b).imag, 0.0);
+        assert_eq!(z.mul_add(a, b).imag, -eps * eps);
+    }
+
+    /// Tests that division avoids overflow for huge divisors and matches the textbook formula otherwise.
+    #[test]
+    fn test_complex_division_overflow() {
+        // |b|² = 2e400 overflows, but the quotient is ordinary
+        let a = Complex::new(3e200, 1e200);
+        let b = Complex::new(1e200, 1e200);
+        let quotient = a / b;
+        assert!((quotient - Complex::new(2.0, -1.0)).magnitude() < 1e-12);
+
+        let tiny = Complex::new(1.0, 0.0) / Complex::new(0.0, 1e200);
+        assert!((tiny - Complex::new(0.0, -1e-200)).magnitude() < 1e-212);
+
+        let mut assigned = a;
+        assigned /= b;
+        assert_eq!(assigned, quotient);
+
+        // Ordinary inputs agree with (a * conj(b)) / |b|²
+        let values = [
+            Complex::new(1.0, 2.0), Complex::new(-3.5, 0.25), Complex::new(0.0, -4.0),
+            Complex::new(7.0, 0.0), Complex::new(-0.125, -6.0),
+        ];
+        for &x in &values {
+            for &y in &values {
+                let textbook = x * y.conjugate() / y.magnitude_squared();
+                assert!((x / y - textbook).magnitude() <= 1e-15 * textbook.magnitude());
+            }
+        }
+    }
+
+    /// Tests that the magnitude of huge and tiny values neither overflows nor underflows.
+    #[test]
+    fn test_complex_magnitude_extremes() {
+        let huge = Complex::new(1e200, 1e200);
+        let expected = 1e200 * 2.0f64.sqrt();
+        assert!(huge.magnitude().is_finite());
+        assert!((huge.magnitude() - expected).abs() <= 1e-15 * expected);
+        assert_eq!(huge.norm(), huge.magnitude());
+
+        let tiny = Complex::new(3e-200, 4e-200);
+        assert!((tiny.magnitude() - 5e-200).abs() <= 1e-15 * 5e-200);
+
+        assert_eq!(Complex::new(-3.0, 4.0).magnitude(), 5.0);
+        assert_eq!(Complex::new(f64::MAX, 0.0).magnitude(), f64::MAX);
+    }
+}
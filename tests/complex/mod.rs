@@ -1,3 +1,4 @@
 mod angle_tests;
 mod complex_tests;
+mod polynomial_tests;
 mod vector_tests;
\ No newline at end of file
@@ -0,0 +1,86 @@
+use rusticle::complex::{eval_polynomial, polynomial_roots, Complex};
+
+/// Test suite for polynomial evaluation and root finding.
+mod polynomial_tests {
+    use super::*;
+
+    /// Asserts that every expected root is matched by a distinct actual root within `tol`.
+    fn assert_roots_eq(actual: Vec<Complex>, expected: &[Complex], tol: f64) {
+        assert_eq!(actual.len(), expected.len());
+        let mut remaining = actual;
+        for e in expected {
+            let position = remaining.iter().position(|a| (*a - *e).magnitude() < tol);
+            assert!(position.is_some(), "no root near {:?} in {:?}", e, remaining);
+            remaining.swap_remove(position.unwrap());
+        }
+    }
+
+    /// Tests roots of polynomials with real and complex roots.
+    #[test]
+    fn test_polynomial_roots() {
+        // x^2 - 1
+        assert_roots_eq(
+            polynomial_roots(&[-1.0, 0.0, 1.0]),
+            &[Complex::new(-1.0, 0.0), Complex::new(1.0, 0.0)],
+            1e-6,
+        );
+
+        // x^2 + 1
+        assert_roots_eq(
+            polynomial_roots(&[1.0, 0.0, 1.0]),
+            &[Complex::new(0.0, -1.0), Complex::new(0.0, 1.0)],
+            1e-6,
+        );
+
+        // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+        assert_roots_eq(
+            polynomial_roots(&[-6.0, 11.0, -6.0, 1.0]),
+            &[Complex::new(1.0, 0.0), Complex::new(2.0, 0.0), Complex::new(3.0, 0.0)],
+            1e-6,
+        );
+
+        // 2x^4 - 2 with trailing zero coefficients: roots ±1, ±i
+        assert_roots_eq(
+            polynomial_roots(&[-2.0, 0.0, 0.0, 0.0, 2.0, 0.0]),
+            &[Complex::new(-1.0, 0.0), Complex::new(0.0, -1.0), Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)],
+            1e-6,
+        );
+
+        // Linear and constant polynomials
+        assert_roots_eq(polynomial_roots(&[3.0, -2.0]), &[Complex::new(1.5, 0.0)], 1e-12);
+        assert!(polynomial_roots(&[5.0]).is_empty());
+    }
+
+    /// Tests that the zero polynomial is rejected.
+    #[test]
+    #[should_panic(expected = "Polynomial must have at least one nonzero coefficient")]
+    fn test_polynomial_roots_zero_polynomial() {
+        let _roots = polynomial_roots(&[0.0, 0.0]); // Should panic
+    }
+
+    /// Tests Horner evaluation against a hand-expanded polynomial.
+    #[test]
+    fn test_eval_polynomial() {
+        // p(z) = (2 - i) + 3z + i z^2 - z^3 at z = 1 + 2i:
+        // z^2 = -3 + 4i, z^3 = -11 - 2i
+        // p = (2 - i) + (3 + 6i) + (-4 - 3i) + (11 + 2i) = 12 + 4i
+        let coeffs = [
+            Complex::new(2.0, -1.0),
+            Complex::new(3.0, 0.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(-1.0, 0.0),
+        ];
+        assert_eq!(eval_polynomial(&coeffs, Complex::new(1.0, 2.0)), Complex::new(12.0, 4.0));
+
+        // Constant and empty polynomials
+        assert_eq!(eval_polynomial(&coeffs[..1], Complex::new(5.0, 5.0)), Complex::new(2.0, -1.0));
+        assert_eq!(eval_polynomial(&[], Complex::new(5.0, 5.0)), Complex::new(0.0, 0.0));
+
+        // The roots found by polynomial_roots are zeros of the polynomial
+        let real = [-6.0, 11.0, -6.0, 1.0];
+        let complex_coeffs: Vec<Complex> = real.iter().map(|&c| Complex::new(c, 0.0)).collect();
+        for root in polynomial_roots(&real) {
+            assert!(eval_polynomial(&complex_coeffs, root).magnitude() < 1e-8);
+        }
+    }
+}
@@ -114,4 +114,645 @@ mod vector_tests {
         let v = ComplexVector::zeros(2);
         let _normalized = v.normalize(); // Should panic
     }
-} 
\ No newline at end of file
+
+    /// Tests the cross product of 3-dimensional vectors.
+    #[test]
+    fn test_cross_product() {
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        let x = ComplexVector::new(vec![one, zero, zero]);
+        let y = ComplexVector::new(vec![zero, one, zero]);
+        let z = ComplexVector::new(vec![zero, zero, one]);
+
+        assert!(x.cross(&y) == z);
+        assert!(y.cross(&x) == -z);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cross product is only defined for 3-dimensional vectors")]
+    fn test_cross_product_wrong_dimension() {
+        let v1 = ComplexVector::zeros(2);
+        let v2 = ComplexVector::zeros(3);
+        let _cross = v1.cross(&v2); // Should panic
+    }
+
+    /// Tests that the outer product of a unit vector with itself is a projector.
+    #[test]
+    fn test_outer_product_projector() {
+        let v = ComplexVector::new(vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, -1.0), Complex::new(0.0, 1.0)
+        ]).normalize();
+        let projector = v.outer_product(&v);
+        assert_eq!(projector.rows(), 3);
+        assert_eq!(projector.cols(), 3);
+
+        let squared = &projector * &projector;
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((*squared.get(i, j) - *projector.get(i, j)).magnitude() < 1e-10);
+            }
+        }
+    }
+
+    /// Tests projection onto and rejection from another vector.
+    #[test]
+    fn test_projection_and_rejection() {
+        let v = ComplexVector::new(vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, -4.0), Complex::new(5.0, 6.0)
+        ]);
+        let e1 = ComplexVector::new(vec![
+            Complex::new(0.0, 0.0), Complex::new(2.0, 0.0), Complex::new(0.0, 0.0)
+        ]);
+
+        let projected = v.project_onto(&e1);
+        assert_eq!(projected.components[0], Complex::new(0.0, 0.0));
+        assert_eq!(projected.components[1], Complex::new(3.0, -4.0));
+        assert_eq!(projected.components[2], Complex::new(0.0, 0.0));
+
+        // The rejection is orthogonal to the vector projected onto
+        let rejected = v.reject_from(&e1);
+        assert!(rejected.inner_product(&e1).magnitude() < 1e-10);
+        assert!(rejected.clone() + projected == v);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot project onto a zero vector")]
+    fn test_project_onto_zero_vector() {
+        let v = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+        let _projected = v.project_onto(&ComplexVector::zeros(2)); // Should panic
+    }
+
+    /// Tests Gram-Schmidt orthonormalization of linearly independent vectors.
+    #[test]
+    fn test_orthonormalize() {
+        let vectors = vec![
+            ComplexVector::new(vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(0.0, -1.0)]),
+            ComplexVector::new(vec![Complex::new(0.0, 1.0), Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)]),
+            ComplexVector::new(vec![Complex::new(2.0, 0.0), Complex::new(0.0, -2.0), Complex::new(1.0, 1.0)]),
+        ];
+
+        let basis = ComplexVector::orthonormalize(&vectors);
+        assert_eq!(basis.len(), 3);
+        for i in 0..basis.len() {
+            for j in 0..basis.len() {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                let product = basis[i].inner_product(&basis[j]);
+                assert!((product - Complex::new(expected, 0.0)).magnitude() < 1e-10);
+            }
+        }
+    }
+
+    /// Tests the Hadamard (element-wise) product.
+    #[test]
+    fn test_hadamard_product() {
+        let v1 = ComplexVector::new(vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, 4.0), Complex::new(0.0, 1.0)
+        ]);
+        let v2 = ComplexVector::new(vec![
+            Complex::new(2.0, 0.0), Complex::new(1.0, -1.0), Complex::new(0.0, 1.0)
+        ]);
+
+        let product = v1.hadamard(&v2);
+        assert_eq!(product.components[0], Complex::new(2.0, 4.0));
+        assert_eq!(product.components[1], Complex::new(7.0, 1.0));
+        assert_eq!(product.components[2], Complex::new(-1.0, 0.0));
+
+        // The operator form matches the method
+        assert!(&v1 * &v2 == product);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must have the same dimension for Hadamard product")]
+    fn test_hadamard_dimension_mismatch() {
+        let _product = ComplexVector::zeros(2).hadamard(&ComplexVector::zeros(3)); // Should panic
+    }
+
+    /// Tests reading and writing components through indexing.
+    #[test]
+    fn test_indexing() {
+        let mut v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+        assert_eq!(v[0], Complex::new(1.0, 2.0));
+        assert_eq!(v[1], Complex::new(3.0, 4.0));
+
+        v[1] = Complex::new(5.0, 6.0);
+        v[0] += Complex::new(1.0, 1.0);
+        assert_eq!(v.components[0], Complex::new(2.0, 3.0));
+        assert_eq!(v.components[1], Complex::new(5.0, 6.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 2 out of bounds for vector of dimension 2")]
+    fn test_index_out_of_bounds() {
+        let v = ComplexVector::zeros(2);
+        let _component = v[2]; // Should panic
+    }
+
+    /// Tests iteration over and collection into vectors.
+    #[test]
+    fn test_iteration() {
+        let v: ComplexVector = (1..=3)
+            .map(|k| Complex::new(k as f64, -(k as f64)))
+            .collect();
+        assert_eq!(v.dimension(), 3);
+        assert_eq!(v[2], Complex::new(3.0, -3.0));
+
+        let sum = v.iter().fold(Complex::new(0.0, 0.0), |acc, c| acc + *c);
+        assert_eq!(sum, Complex::new(6.0, -6.0));
+
+        let mut borrowed_sum = Complex::new(0.0, 0.0);
+        for c in &v {
+            borrowed_sum += *c;
+        }
+        assert_eq!(borrowed_sum, sum);
+
+        let mut doubled = v.clone();
+        for c in doubled.iter_mut() {
+            *c *= 2.0;
+        }
+        let owned_sum = doubled.into_iter().fold(Complex::new(0.0, 0.0), |acc, c| acc + c);
+        assert_eq!(owned_sum, Complex::new(12.0, -12.0));
+    }
+
+    /// Tests element-wise conjugation.
+    #[test]
+    fn test_conjugate() {
+        let a = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, -4.0)]);
+        let b = ComplexVector::new(vec![Complex::new(5.0, 6.0), Complex::new(-7.0, 8.0)]);
+
+        assert!(a.conjugate().conjugate() == a);
+
+        // The inner product conjugates its second argument
+        let summed = a.hadamard(&b.conjugate())
+            .iter()
+            .fold(Complex::new(0.0, 0.0), |acc, c| acc + *c);
+        assert_eq!(a.inner_product(&b), summed);
+
+        let mut c = a.clone();
+        c.conjugate_mut();
+        assert!(c == a.conjugate());
+    }
+
+    /// Tests division by real and complex scalars.
+    #[test]
+    fn test_scalar_division() {
+        let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(-3.0, 4.0)]);
+
+        let halved = v.clone() / 2.0;
+        let scaled = v.clone() * 0.5;
+        for i in 0..v.dimension() {
+            assert!((halved[i] - scaled[i]).magnitude() < 1e-10);
+        }
+
+        let divided = v.clone() / Complex::new(0.0, 1.0);
+        assert!((divided[0] - Complex::new(2.0, -1.0)).magnitude() < 1e-10);
+        assert!((divided[1] - Complex::new(4.0, 3.0)).magnitude() < 1e-10);
+
+        // Division by zero does not panic
+        let infinite = v / 0.0;
+        assert!(infinite[0].real.is_infinite());
+        assert!(infinite[0].imag.is_infinite());
+    }
+
+    /// Tests multiplication by a complex scalar.
+    #[test]
+    fn test_complex_scalar_multiplication() {
+        let v = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 2.0), Complex::new(3.0, 4.0)]);
+
+        // Multiplying by i rotates every component by 90 degrees
+        let rotated = v.clone() * Complex::I;
+        assert_eq!(rotated[0], Complex::new(0.0, 1.0));
+        assert_eq!(rotated[1], Complex::new(-2.0, 0.0));
+        assert_eq!(rotated[2], Complex::new(-4.0, 3.0));
+        for i in 0..v.dimension() {
+            assert!((rotated[i].magnitude() - v[i].magnitude()).abs() < 1e-10);
+        }
+
+        assert!(Complex::I * v.clone() == rotated);
+    }
+
+    /// Tests the angle between orthogonal and parallel vectors.
+    #[test]
+    fn test_angle_between() {
+        let v1 = ComplexVector::new(vec![Complex::new(1.0, 1.0), Complex::new(0.0, 0.0)]);
+        let v2 = ComplexVector::new(vec![Complex::new(0.0, 0.0), Complex::new(2.0, -1.0)]);
+        assert!((v1.angle_between(&v2).to_degrees() - 90.0).abs() < 1e-10);
+
+        let parallel = v1.clone() * Complex::new(0.0, 3.0);
+        assert!(v1.angle_between(&parallel).to_degrees().abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot compute the angle with a zero vector")]
+    fn test_angle_between_zero_vector() {
+        let v = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]);
+        let _angle = v.angle_between(&ComplexVector::zeros(2)); // Should panic
+    }
+
+    /// Tests the distance between two vectors.
+    #[test]
+    fn test_distance() {
+        let v1 = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+        let v2 = ComplexVector::new(vec![Complex::new(2.0, 0.0), Complex::new(0.0, 4.0)]);
+
+        // |(-1+2i)|^2 + |3|^2 = 5 + 9 = 14
+        assert!((v1.distance_squared(&v2) - 14.0).abs() < 1e-10);
+        assert!((v1.distance(&v2) - 14.0f64.sqrt()).abs() < 1e-10);
+        assert!((v1.distance(&v2) - (v1.clone() - v2.clone()).norm()).abs() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must have the same dimension for distance")]
+    fn test_distance_dimension_mismatch() {
+        let _distance = ComplexVector::zeros(2).distance(&ComplexVector::zeros(3)); // Should panic
+    }
+
+    /// Tests building a vector incrementally with `with_capacity` and `push`.
+    #[test]
+    fn test_with_capacity_and_push() {
+        let values = [Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5), Complex::new(0.0, -1.0)];
+        let mut v = ComplexVector::with_capacity(values.len());
+        assert_eq!(v.dimension(), 0);
+        for &c in values.iter() {
+            v.push(c);
+        }
+        assert!(v == ComplexVector::new(values.to_vec()));
+    }
+
+    /// Tests that `dot` does not conjugate, unlike `inner_product`.
+    #[test]
+    fn test_dot_without_conjugation() {
+        let v1 = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+        let v2 = ComplexVector::new(vec![Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)]);
+
+        // (1+2i)(5+6i) + (3+4i)(7+8i) = (-7+16i) + (-11+52i)
+        assert_eq!(v1.dot(&v2), Complex::new(-18.0, 68.0));
+        assert_eq!(v1.inner_product(&v2), Complex::new(70.0, 8.0));
+        assert_eq!(v1.dot(&v2), v2.dot(&v1));
+        assert_eq!(v1.dot(&v2.conjugate()), v1.inner_product(&v2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must have the same dimension for dot product")]
+    fn test_dot_dimension_mismatch() {
+        let _dot = ComplexVector::zeros(2).dot(&ComplexVector::zeros(3)); // Should panic
+    }
+
+    /// Tests fallible normalization.
+    #[test]
+    fn test_try_normalize() {
+        assert!(ComplexVector::zeros(3).try_normalize().is_none());
+        assert!(ComplexVector::new(vec![]).try_normalize().is_none());
+
+        let v = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 5.0)]);
+        let normalized = v.try_normalize().unwrap();
+        assert!(normalized == v.normalize());
+        assert!((normalized.norm() - 1.0).abs() < 1e-10);
+    }
+
+    /// Tests normalizing a vector in place.
+    #[test]
+    fn test_normalize_mut() {
+        let v = ComplexVector::new(vec![Complex::new(1.0, -2.0), Complex::new(2.0, 0.0)]);
+        let mut w = v.clone();
+        w.normalize_mut();
+        assert!(w == v.normalize());
+        assert!((w.norm() - 1.0).abs() < 1e-10);
+        assert!((w[0] - Complex::new(1.0 / 3.0, -2.0 / 3.0)).magnitude() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot normalize a zero vector")]
+    fn test_normalize_mut_zero_vector() {
+        let mut v = ComplexVector::zeros(2);
+        v.normalize_mut(); // Should panic
+    }
+
+    /// Tests clearing tiny parts of every component.
+    #[test]
+    fn test_chop() {
+        let v = ComplexVector::new(vec![
+            Complex::new(1.0, 1e-16),
+            Complex::new(-1e-17, -2.0),
+            Complex::new(0.5, 0.25)
+        ]);
+        let expected = ComplexVector::new(vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(0.0, -2.0),
+            Complex::new(0.5, 0.25)
+        ]);
+        assert!(v.chop(1e-12) == expected);
+    }
+
+    /// Tests the tensor product of two vectors.
+    #[test]
+    fn test_tensor() {
+        let a = ComplexVector::new(vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)]);
+        let b = ComplexVector::new(vec![Complex::new(3.0, 0.0), Complex::new(1.0, -1.0), Complex::new(0.0, 0.5)]);
+        let product = a.tensor(&b);
+        assert_eq!(product.dimension(), 6);
+        for i in 0..a.dimension() {
+            for j in 0..b.dimension() {
+                assert_eq!(product[i * b.dimension() + j], a[i] * b[j]);
+            }
+        }
+
+        let u = a.normalize();
+        let v = b.normalize();
+        assert!((u.tensor(&v).norm() - 1.0).abs() < 1e-10);
+    }
+
+    /// Tests summing and averaging components.
+    #[test]
+    fn test_sum_and_mean() {
+        let v = ComplexVector::new(vec![
+            Complex::new(1.0, 2.0),
+            Complex::new(-3.0, 0.5),
+            Complex::new(4.0, -1.0),
+            Complex::new(0.0, 2.5)
+        ]);
+        assert_eq!(v.sum(), Complex::new(2.0, 4.0));
+        assert_eq!(v.mean(), Complex::new(0.5, 1.0));
+        assert_eq!(ComplexVector::new(vec![]).sum(), Complex::new(0.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot compute the mean of an empty vector")]
+    fn test_mean_empty() {
+        let _mean = ComplexVector::new(vec![]).mean(); // Should panic
+    }
+
+    /// Tests parsing a vector from a bracketed string.
+    #[test]
+    fn test_parse_vector() {
+        let v: ComplexVector = "[1+2i, 3-4i, 5i]".parse().unwrap();
+        assert!(v == ComplexVector::new(vec![
+            Complex::new(1.0, 2.0),
+            Complex::new(3.0, -4.0),
+            Complex::new(0.0, 5.0)
+        ]));
+
+        let spaced: ComplexVector = "  [ -1.5 ,2i,  0 ]  ".parse().unwrap();
+        assert_eq!(spaced.dimension(), 3);
+        assert_eq!(spaced[0], Complex::new(-1.5, 0.0));
+
+        let empty: ComplexVector = "[]".parse().unwrap();
+        assert_eq!(empty.dimension(), 0);
+
+        // Debug output parses back to the same vector
+        let reparsed: ComplexVector = format!("{:?}", v).parse().unwrap();
+        assert!(reparsed == v);
+    }
+
+    /// Tests that malformed vector strings are rejected.
+    #[test]
+    fn test_parse_vector_errors() {
+        let malformed = "[1+2i, abc, 3]".parse::<ComplexVector>();
+        assert!(malformed.unwrap_err().starts_with("Invalid element 1"));
+        assert!("1+2i, 3".parse::<ComplexVector>().is_err());
+        assert!("[1, , 2]".parse::<ComplexVector>().is_err());
+    }
+
+    /// Tests per-component magnitudes and angles.
+    #[test]
+    fn test_magnitudes_and_arguments() {
+        let v = ComplexVector::new(vec![
+            Complex::new(3.0, 4.0),
+            Complex::new(-1.0, 1.0),
+            Complex::new(0.0, -2.5),
+            Complex::new(0.0, 0.0),
+        ]);
+
+        let magnitudes = v.magnitudes();
+        let arguments = v.arguments();
+        assert_eq!(magnitudes.len(), v.dimension());
+        assert_eq!(arguments.len(), v.dimension());
+        for (i, c) in v.iter().enumerate() {
+            assert_eq!(magnitudes[i], c.magnitude());
+            assert_eq!(arguments[i].to_radians(), c.angle().to_radians());
+        }
+
+        assert!(ComplexVector::new(vec![]).magnitudes().is_empty());
+    }
+
+    /// Tests the radix-2 FFT against a direct DFT and its inverse.
+    #[test]
+    fn test_fft() {
+        use std::f64::consts::PI;
+
+        // Round trip for several power-of-two lengths
+        for n in [1, 2, 4, 8, 64] {
+            let v: ComplexVector = (0..n)
+                .map(|k| Complex::new((k as f64 * 0.7).sin(), (k as f64 * 1.3).cos() - 0.5))
+                .collect();
+            assert!((v.fft().ifft() - v.clone()).norm() < 1e-9);
+        }
+
+        // A constant vector transforms to a spike at index 0
+        let constant = ComplexVector::new(vec![Complex::new(2.0, -1.0); 8]);
+        let spectrum = constant.fft();
+        assert!((spectrum[0] - Complex::new(16.0, -8.0)).magnitude() < 1e-12);
+        for k in 1..8 {
+            assert!(spectrum[k].magnitude() < 1e-12);
+        }
+
+        // Matches the direct O(n^2) definition
+        let v: ComplexVector = (0..16).map(|k| Complex::new(k as f64, (k * k) as f64 * 0.1)).collect();
+        let spectrum = v.fft();
+        for k in 0..16 {
+            let mut expected = Complex::new(0.0, 0.0);
+            for j in 0..16 {
+                let theta = -2.0 * PI * (j * k) as f64 / 16.0;
+                expected += v[j] * Complex::new(theta.cos(), theta.sin());
+            }
+            assert!((spectrum[k] - expected).magnitude() < 1e-9);
+        }
+    }
+
+    /// Tests that the FFT rejects lengths that are not powers of two.
+    #[test]
+    #[should_panic(expected = "FFT length must be a power of two")]
+    fn test_fft_non_power_of_two() {
+        let v = ComplexVector::zeros(6);
+        let _spectrum = v.fft(); // Should panic
+    }
+
+    /// Tests linear convolution against a hand-computed result.
+    #[test]
+    fn test_convolve() {
+        // (1 + i, 2, -1) * (3, i) = (3+3i, 5+i, -3+2i, -i)
+        let a = ComplexVector::new(vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(-1.0, 0.0)]);
+        let b = ComplexVector::new(vec![Complex::new(3.0, 0.0), Complex::new(0.0, 1.0)]);
+        let expected = vec![
+            Complex::new(3.0, 3.0),
+            Complex::new(5.0, 1.0),
+            Complex::new(-3.0, 2.0),
+            Complex::new(0.0, -1.0),
+        ];
+        assert_eq!(a.convolve(&b).components, expected);
+        assert_eq!(b.convolve(&a).components, expected);
+
+        // Convolving with a unit impulse is the identity
+        let impulse = ComplexVector::new(vec![Complex::new(1.0, 0.0)]);
+        assert_eq!(a.convolve(&impulse), a);
+
+        assert_eq!(a.convolve(&ComplexVector::new(vec![])).dimension(), 0);
+    }
+
+    /// Tests slicing and splitting, including empty and full-range boundaries.
+    #[test]
+    fn test_slice_and_split_at() {
+        let v: ComplexVector = (0..6).map(|k| Complex::new(k as f64, -(k as f64))).collect();
+
+        let middle = v.slice(2..5);
+        assert_eq!(middle.components, v.components[2..5].to_vec());
+
+        assert_eq!(v.slice(0..6), v);
+        assert_eq!(v.slice(3..3).dimension(), 0);
+        assert_eq!(v.slice(6..6).dimension(), 0);
+
+        let (left, right) = v.split_at(2);
+        assert_eq!(left, v.slice(0..2));
+        assert_eq!(right, v.slice(2..6));
+
+        let (empty, all) = v.split_at(0);
+        assert_eq!(empty.dimension(), 0);
+        assert_eq!(all, v);
+        let (all, empty) = v.split_at(6);
+        assert_eq!(all, v);
+        assert_eq!(empty.dimension(), 0);
+    }
+
+    /// Tests that slicing past the end panics.
+    #[test]
+    #[should_panic(expected = "Slice range 2..5 out of bounds for vector of dimension 4")]
+    fn test_slice_out_of_bounds() {
+        let v = ComplexVector::zeros(4);
+        let _window = v.slice(2..5); // Should panic
+    }
+
+    /// Tests that splitting past the end panics.
+    #[test]
+    #[should_panic(expected = "Split index 5 out of bounds for vector of dimension 4")]
+    fn test_split_at_out_of_bounds() {
+        let v = ComplexVector::zeros(4);
+        let _halves = v.split_at(5); // Should panic
+    }
+
+    /// Tests that concatenating and then slicing recovers the original parts.
+    #[test]
+    fn test_concat() {
+        let a = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]);
+        let b = ComplexVector::new(vec![Complex::new(-1.0, 0.0)]);
+        let c = ComplexVector::new(vec![Complex::new(0.0, 5.0), Complex::new(6.0, -6.0), Complex::new(7.0, 0.0)]);
+
+        let joined = a.concat(&b);
+        assert_eq!(joined.slice(0..2), a);
+        assert_eq!(joined.slice(2..3), b);
+
+        let all = ComplexVector::concat_all(&[a.clone(), b.clone(), c.clone()]);
+        assert_eq!(all.dimension(), 6);
+        assert_eq!(all.slice(0..2), a);
+        assert_eq!(all.slice(2..3), b);
+        assert_eq!(all.slice(3..6), c);
+        assert_eq!(all, a.concat(&b).concat(&c));
+
+        let empty = ComplexVector::new(vec![]);
+        assert_eq!(a.concat(&empty), a);
+        assert_eq!(empty.concat(&a), a);
+        assert_eq!(ComplexVector::concat_all(&[]).dimension(), 0);
+    }
+
+    /// Tests zero-padding and truncation.
+    #[test]
+    fn test_resize_and_pad_to_pow2() {
+        let v = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0), Complex::new(5.0, 6.0)]);
+
+        let padded = v.pad_to_pow2();
+        assert_eq!(padded.dimension(), 4);
+        assert_eq!(padded.slice(0..3), v);
+        assert_eq!(padded[3], Complex::new(0.0, 0.0));
+        assert_eq!(padded.resize(3), v);
+
+        assert_eq!(v.resize(1).components, vec![Complex::new(1.0, 2.0)]);
+        assert_eq!(v.resize(0).dimension(), 0);
+        assert_eq!(v.resize(3), v);
+
+        // Power-of-two lengths are unchanged and the result is FFT-ready
+        assert_eq!(padded.pad_to_pow2(), padded);
+        assert_eq!(ComplexVector::new(vec![]).pad_to_pow2().dimension(), 1);
+        assert_eq!(ComplexVector::zeros(9).pad_to_pow2().fft().dimension(), 16);
+    }
+
+    /// Tests that the norm stays finite for huge components.
+    #[test]
+    fn test_norm_overflow_safe() {
+        let v = ComplexVector::new(vec![Complex::new(1e200, 0.0), Complex::new(3.0, 4.0), Complex::new(0.0, 1e200)]);
+        let expected = 1e200 * 2.0f64.sqrt();
+        assert!(v.norm_squared().is_infinite());
+        assert!(v.norm().is_finite());
+        assert!((v.norm() - expected).abs() <= 1e-15 * expected);
+
+        let single = ComplexVector::new(vec![Complex::new(-1e300, 0.0)]);
+        assert_eq!(single.norm(), 1e300);
+
+        // Ordinary, zero, empty and non-finite vectors
+        let ordinary = ComplexVector::new(vec![Complex::new(3.0, 4.0), Complex::new(0.0, 5.0)]);
+        assert!((ordinary.norm() - 50.0f64.sqrt()).abs() < 1e-14);
+        assert_eq!(ComplexVector::zeros(3).norm(), 0.0);
+        assert_eq!(ComplexVector::new(vec![]).norm(), 0.0);
+        assert!(ComplexVector::new(vec![Complex::new(f64::INFINITY, 0.0)]).norm().is_infinite());
+        assert!(ComplexVector::new(vec![Complex::new(f64::NAN, 0.0), Complex::new(1.0, 0.0)]).norm().is_nan());
+        assert!(ComplexVector::new(vec![Complex::new(f64::NAN, 0.0), Complex::new(f64::INFINITY, 0.0)]).norm().is_nan());
+        assert!(ComplexVector::new(vec![Complex::new(f64::INFINITY, 0.0), Complex::new(f64::NAN, 0.0)]).norm().is_nan());
+    }
+
+    /// Tests the weighted inner product against the standard one.
+    #[test]
+    fn test_weighted_inner_product() {
+        let v1 = ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0), Complex::new(-1.0, 0.5)]);
+        let v2 = ComplexVector::new(vec![Complex::new(5.0, 6.0), Complex::new(7.0, 8.0), Complex::new(0.0, -2.0)]);
+
+        // Unit weights reproduce the standard inner product
+        assert_eq!(v1.weighted_inner_product(&v2, &[1.0; 3]), v1.inner_product(&v2));
+
+        // Each weight scales its own term
+        let weights = [2.0, 0.0, -0.5];
+        let expected = v1[0] * v2[0].conjugate() * 2.0 + v1[2] * v2[2].conjugate() * -0.5;
+        assert!((v1.weighted_inner_product(&v2, &weights) - expected).magnitude() < 1e-12);
+
+        // Positive weights give a real, positive weighted squared norm
+        let norm_squared = v1.weighted_inner_product(&v1, &[1.0, 2.0, 3.0]);
+        assert_eq!(norm_squared.imag, 0.0);
+        assert_eq!(norm_squared.real, 5.0 + 2.0 * 25.0 + 3.0 * 1.25);
+    }
+
+    /// Tests that a weight count mismatch panics.
+    #[test]
+    #[should_panic(expected = "Weights must match the vector dimension")]
+    fn test_weighted_inner_product_weight_mismatch() {
+        let v = ComplexVector::zeros(3);
+        let _product = v.weighted_inner_product(&v, &[1.0, 1.0]); // Should panic
+    }
+
+    /// Tests that differencing the running sums recovers the tail of the vector.
+    #[test]
+    fn test_cumsum_and_diff() {
+        let v = ComplexVector::new(vec![
+            Complex::new(1.0, -1.0),
+            Complex::new(2.5, 0.0),
+            Complex::new(-4.0, 3.0),
+            Complex::new(0.0, 0.5),
+        ]);
+
+        let sums = v.cumsum();
+        assert_eq!(sums.dimension(), 4);
+        assert_eq!(sums[0], v[0]);
+        assert_eq!(sums[3], v.sum());
+
+        assert_eq!(sums.diff(), v.slice(1..4));
+
+        assert_eq!(ComplexVector::new(vec![]).cumsum().dimension(), 0);
+        assert_eq!(ComplexVector::new(vec![]).diff().dimension(), 0);
+        assert_eq!(ComplexVector::new(vec![Complex::new(1.0, 1.0)]).diff().dimension(), 0);
+    }
+}
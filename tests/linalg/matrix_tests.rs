@@ -1,5 +1,6 @@
-use rusticle::complex::Complex;
-use rusticle::linalg::matrix::Matrix;
+use rusticle::complex::{Angle, Complex, Complex32, ComplexVector};
+use rusticle::linalg::matrix::{Matrix, One, Zero};
+use rusticle::linalg::Vector;
 
 /// Test suite for the Matrix type.
 /// 
@@ -58,6 +59,13 @@ mod matrix_tests {
         assert_eq!(*identity.get(2, 2), Complex::new(1.0, 0.0));
         assert_eq!(*identity.get(0, 1), Complex::new(0.0, 0.0));
         
+        // Test real identity matrix
+        let real_identity = Matrix::<f64>::identity(3);
+        assert_eq!(real_identity, Matrix::new(3, 3, vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]));
+        let real = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(&real_identity * &real, real);
+        assert_eq!(Matrix::<f64>::identity(0).rows(), 0);
+        
         // Test conjugate transpose
         let matrix = Matrix::new(2, 2, vec![
             Complex::new(1.0, 2.0), Complex::new(3.0, 4.0),
@@ -70,6 +78,117 @@ mod matrix_tests {
         assert_eq!(*ct.get(1, 1), Complex::new(7.0, -8.0));
     }
 
+    /// Tests building rotation matrices from angles.
+    #[test]
+    fn test_rotation() {
+        let quarter_turn = Matrix::rotation(Angle::from_degrees(90.0));
+        assert!(quarter_turn.is_unitary());
+
+        let e0 = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+        let rotated = (&quarter_turn * &e0.to_matrix()).col(0);
+        assert!((rotated[0] - Complex::new(0.0, 0.0)).magnitude() < 1e-10);
+        assert!((rotated[1] - Complex::new(1.0, 0.0)).magnitude() < 1e-10);
+
+        for degrees in [0.0, 30.0, -45.0, 200.0] {
+            assert!(Matrix::rotation(Angle::from_degrees(degrees)).is_unitary());
+        }
+        let composed = &Matrix::rotation(Angle::from_degrees(30.0)) * &Matrix::rotation(Angle::from_degrees(60.0));
+        assert!(composed.approx_eq(&quarter_turn, 1e-10));
+    }
+
+    /// Tests the Pauli matrices and common single-qubit gates.
+    #[test]
+    fn test_quantum_gates() {
+        let identity = Matrix::identity(2);
+        let (x, y, z) = (Matrix::pauli_x(), Matrix::pauli_y(), Matrix::pauli_z());
+        let gates = [x.clone(), y.clone(), z.clone(), Matrix::hadamard(), Matrix::phase(Angle::from_degrees(45.0))];
+        for gate in gates.iter() {
+            assert!(gate.is_unitary());
+        }
+
+        assert!(&x * &x == identity);
+        assert!(&y * &y == identity);
+        assert!(&z * &z == identity);
+
+        // XY = iZ
+        assert!(&x * &y == z.clone() * Complex::I);
+
+        // HZH = X
+        let h = Matrix::hadamard();
+        assert!((&(&h * &z) * &h).approx_eq(&x, 1e-10));
+
+        // Two quarter-turn phase gates make a Z gate
+        let s = Matrix::phase(Angle::from_degrees(90.0));
+        assert!((&s * &s).approx_eq(&z, 1e-10));
+    }
+
+    /// Tests embedding single-qubit gates in a multi-qubit register.
+    #[test]
+    fn test_embed_gate() {
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+        let embedded = Matrix::embed_gate(&Matrix::hadamard(), 0, 2);
+        let expected = Matrix::new(4, 4, vec![
+            h, 0.0, h, 0.0,
+            0.0, h, 0.0, h,
+            h, 0.0, -h, 0.0,
+            0.0, h, 0.0, -h
+        ].into_iter().map(Complex::from).collect());
+        assert!(embedded.approx_eq(&expected, 1e-12));
+        assert!(embedded.is_unitary());
+
+        let middle = Matrix::embed_gate(&Matrix::pauli_z(), 1, 3);
+        assert_eq!(middle.rows(), 8);
+        assert!(middle == Matrix::identity(2).kronecker(&Matrix::pauli_z()).kronecker(&Matrix::identity(2)));
+        assert!(Matrix::embed_gate(&Matrix::pauli_x(), 0, 1) == Matrix::pauli_x());
+    }
+
+    #[test]
+    #[should_panic(expected = "Target qubit 2 out of bounds for 2 qubits")]
+    fn test_embed_gate_target_out_of_bounds() {
+        let _gate = Matrix::embed_gate(&Matrix::pauli_x(), 2, 2); // Should panic
+    }
+
+    /// Tests parsing a complex matrix from a delimited string.
+    #[test]
+    fn test_from_str_rows() {
+        let matrix = Matrix::from_str_rows("1,2;3,4").unwrap();
+        assert!(matrix == Matrix::new(2, 2, vec![
+            Complex::new(1.0, 0.0), Complex::new(2.0, 0.0),
+            Complex::new(3.0, 0.0), Complex::new(4.0, 0.0)
+        ]));
+
+        let complex = Matrix::from_str_rows(" 1+2i , -i ; 0.5 , 3-4i ").unwrap();
+        assert_eq!(*complex.get(0, 1), Complex::new(0.0, -1.0));
+        assert_eq!(*complex.get(1, 1), Complex::new(3.0, -4.0));
+
+        let column = Matrix::from_str_rows("1; 2; 3").unwrap();
+        assert_eq!((column.rows(), column.cols()), (3, 1));
+        assert_eq!(Matrix::from_str_rows("").unwrap().rows(), 0);
+    }
+
+    /// Tests that ragged or malformed matrix strings are rejected.
+    #[test]
+    fn test_from_str_rows_errors() {
+        assert_eq!(Matrix::from_str_rows("1,2;3").unwrap_err(), "Row 1 has 1 elements, expected 2");
+        assert!(Matrix::from_str_rows("1,x;3,4").unwrap_err().starts_with("Invalid element at (0, 1)"));
+        assert!(Matrix::from_str_rows("1,2;").is_err());
+    }
+
+    /// Tests building diagonal matrices and extracting the diagonal.
+    #[test]
+    fn test_from_diagonal_and_diagonal() {
+        let entries = vec![Complex::new(1.0, -1.0), Complex::new(0.0, 2.0), Complex::new(-3.0, 0.5)];
+        let matrix = Matrix::from_diagonal(&entries);
+        assert_eq!(matrix.rows(), 3);
+        assert_eq!(matrix.cols(), 3);
+        assert_eq!(*matrix.get(2, 0), Complex::new(0.0, 0.0));
+        assert!(matrix.diagonal() == ComplexVector::new(entries));
+        assert!(Matrix::from_diagonal(&[Complex::new(1.0, 0.0); 4]) == Matrix::identity(4));
+
+        let wide = Matrix::new(2, 3, (1..=6).map(|x| Complex::new(x as f64, 0.0)).collect());
+        assert!(wide.diagonal() == ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(5.0, 0.0)]));
+    }
+
     /// Tests complex number specific operations.
     #[test]
     fn test_complex_operations() {
@@ -88,4 +207,1236 @@ mod matrix_tests {
         ]);
         assert!(!non_unitary.is_unitary());
     }
-} 
\ No newline at end of file
+
+    /// Tests transposing a non-square real matrix.
+    #[test]
+    fn test_transpose() {
+        let matrix = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let transposed = matrix.transpose();
+        assert_eq!(transposed.rows(), 3);
+        assert_eq!(transposed.cols(), 2);
+        for i in 0..matrix.rows() {
+            for j in 0..matrix.cols() {
+                assert_eq!(transposed.get(j, i), matrix.get(i, j));
+            }
+        }
+        assert_eq!(transposed.transpose(), matrix);
+    }
+
+    /// Tests that owned and borrowed addition and subtraction agree.
+    #[test]
+    fn test_borrowed_addition_and_subtraction() {
+        let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::new(2, 2, vec![0.5, -1.0, 2.0, 8.0]);
+
+        assert_eq!(&a + &b, Matrix::new(2, 2, vec![1.5, 1.0, 5.0, 12.0]));
+        assert_eq!(&a - &b, Matrix::new(2, 2, vec![0.5, 3.0, 1.0, -4.0]));
+        assert_eq!(&a + &b, a.clone() + b.clone());
+        assert_eq!(&a - &b, a.clone() - b.clone());
+
+        let c = pseudo_random_complex_matrix(3, 4, 7);
+        let d = pseudo_random_complex_matrix(3, 4, 8);
+        assert!(&c + &d == c.clone() + d.clone());
+        assert!(&c - &d == c.clone() - d.clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrices must have same number of columns")]
+    fn test_borrowed_addition_mismatch() {
+        let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::new(2, 1, vec![1.0, 2.0]);
+        let _sum = &a + &b; // Should panic
+    }
+
+    /// Tests multiplication of real matrices.
+    #[test]
+    fn test_real_matrix_multiplication() {
+        let a = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = Matrix::new(3, 2, vec![7.0, 8.0, 9.0, 10.0, 11.0, 12.0]);
+
+        let product = &a * &b;
+        assert_eq!(product.rows(), 2);
+        assert_eq!(product.cols(), 2);
+        assert_eq!(product, Matrix::new(2, 2, vec![58.0, 64.0, 139.0, 154.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of columns in first matrix must match number of rows in second matrix")]
+    fn test_real_matrix_multiplication_mismatch() {
+        let a = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let _product = &a * &a; // Should panic
+    }
+
+    /// Builds a deterministic pseudo-random complex matrix with entries in [-1, 1).
+    fn pseudo_random_complex_matrix(rows: usize, cols: usize, seed: u64) -> Matrix<Complex> {
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+        };
+        let data = (0..rows * cols).map(|_| Complex::new(next(), next())).collect();
+        Matrix::new(rows, cols, data)
+    }
+
+    /// Computes a matrix product with the naive `i-j-k` loop as a reference.
+    fn naive_product(a: &Matrix<Complex>, b: &Matrix<Complex>) -> Matrix<Complex> {
+        let mut expected = Matrix::zeros(a.rows(), b.cols());
+        for i in 0..a.rows() {
+            for j in 0..b.cols() {
+                let mut sum = Complex::new(0.0, 0.0);
+                for k in 0..a.cols() {
+                    sum += *a.get(i, k) * *b.get(k, j);
+                }
+                expected.set(i, j, sum);
+            }
+        }
+        expected
+    }
+
+    /// Tests that complex matrix multiplication, parallel with the `rayon`
+    /// feature, matches a serial reference product exactly.
+    #[test]
+    fn test_complex_matrix_multiplication_matches_serial() {
+        let a = pseudo_random_complex_matrix(32, 32, 1);
+        let b = pseudo_random_complex_matrix(32, 32, 2);
+        assert!(&a * &b == naive_product(&a, &b));
+    }
+
+    /// Tests that the cache-friendly loop order leaves products unchanged on
+    /// larger, non-square inputs.
+    #[test]
+    fn test_complex_matrix_multiplication_large() {
+        let a = pseudo_random_complex_matrix(64, 64, 3);
+        let b = pseudo_random_complex_matrix(64, 64, 4);
+        assert!(&a * &b == naive_product(&a, &b));
+
+        let tall = pseudo_random_complex_matrix(64, 17, 5);
+        let wide = pseudo_random_complex_matrix(17, 40, 6);
+        assert!(&tall * &wide == naive_product(&tall, &wide));
+
+        let real_a = a.map(|z| z.real);
+        let real_b = b.map(|z| z.imag);
+        let real_product = &real_a * &real_b;
+        for i in 0..64 {
+            for j in 0..64 {
+                let expected: f64 = (0..64).map(|k| real_a.get(i, k) * real_b.get(k, j)).sum();
+                assert!((real_product.get(i, j) - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    /// Tests the matrix-vector multiplication operator.
+    #[test]
+    fn test_matrix_vector_operator() {
+        let m = pseudo_random_complex_matrix(3, 4, 9);
+        let v = ComplexVector::new(vec![
+            Complex::new(1.0, 0.5), Complex::new(-2.0, 0.0),
+            Complex::new(0.0, 3.0), Complex::new(0.25, -1.0)
+        ]);
+
+        let product = &m * &v;
+        assert_eq!(product.dimension(), 3);
+
+        let mut expected = m.clone();
+        expected.mul_vector(&v);
+        assert!(product == ComplexVector::from_matrix(&expected));
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix columns must match vector dimension")]
+    fn test_matrix_vector_operator_mismatch() {
+        let m = Matrix::<Complex>::identity(2);
+        let _product = &m * &ComplexVector::zeros(3); // Should panic
+    }
+
+    /// Tests applying a matrix to a batch of vectors.
+    #[test]
+    fn test_mul_vectors() {
+        let m = pseudo_random_complex_matrix(4, 3, 10);
+        let vectors: Vec<ComplexVector> = (0..5)
+            .map(|seed| pseudo_random_complex_matrix(3, 1, 20 + seed).col(0))
+            .collect();
+
+        let batched = m.mul_vectors(&vectors);
+        assert_eq!(batched.len(), vectors.len());
+        for (result, v) in batched.iter().zip(vectors.iter()) {
+            let mut expected = m.clone();
+            expected.mul_vector(v);
+            assert!(*result == ComplexVector::from_matrix(&expected));
+        }
+        assert!(m.mul_vectors(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix columns must match vector dimension")]
+    fn test_mul_vectors_mismatch() {
+        let m = Matrix::<Complex>::identity(2);
+        let _products = m.mul_vectors(&[ComplexVector::zeros(2), ComplexVector::zeros(3)]); // Should panic
+    }
+
+    /// Tests the determinant of real and complex matrices.
+    #[test]
+    fn test_determinant() {
+        let matrix = Matrix::new(3, 3, vec![
+            2.0, -3.0, 1.0,
+            2.0, 0.0, -1.0,
+            1.0, 4.0, 5.0
+        ]);
+        assert!((matrix.determinant() - 49.0).abs() < 1e-10);
+
+        // Rows are linearly dependent
+        let singular = Matrix::new(3, 3, vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0
+        ]);
+        assert!(singular.determinant().abs() < 1e-10);
+
+        let complex = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, 0.0),
+            Complex::new(0.0, 1.0), Complex::new(2.0, -1.0)
+        ]);
+        // (1+2i)(2-i) - 3i = 4+3i - 3i = 4
+        assert!((complex.determinant() - Complex::new(4.0, 0.0)).magnitude() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Determinant is only defined for square matrices")]
+    fn test_determinant_non_square() {
+        let matrix = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let _det = matrix.determinant(); // Should panic
+    }
+
+    /// Tests solving a system with multiple right-hand sides.
+    #[test]
+    fn test_solve_matrix() {
+        let a = Matrix::new(3, 3, vec![2.0, 1.0, -1.0, -3.0, -1.0, 2.0, -2.0, 1.0, 2.0]);
+
+        // Solving against the identity yields the inverse
+        let x = a.solve_matrix(&Matrix::identity(3)).unwrap();
+        assert!(x.approx_eq(&a.inverse().unwrap(), 1e-10));
+
+        let b = Matrix::new(3, 2, vec![8.0, 1.0, -11.0, 0.0, -3.0, 2.0]);
+        let x = a.solve_matrix(&b).unwrap();
+        assert!((&a * &x).approx_eq(&b, 1e-10));
+        assert!((x.col(0) - a.solve(&b.col(0)).unwrap()).norm() < 1e-10);
+
+        let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert!(singular.solve_matrix(&Matrix::identity(2)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Right-hand side rows must match matrix rows")]
+    fn test_solve_matrix_mismatch() {
+        let a = Matrix::<f64>::identity(2);
+        let _x = a.solve_matrix(&Matrix::<f64>::identity(3)); // Should panic
+    }
+
+    /// Tests the pivot-based singularity check.
+    #[test]
+    fn test_is_singular() {
+        let well_conditioned = Matrix::new(3, 3, vec![4.0, 1.0, 0.0, 1.0, 4.0, 1.0, 0.0, 1.0, 4.0]);
+        assert!(!well_conditioned.is_singular(1e-10));
+
+        // Third row is the sum of the first two
+        let rank_deficient = Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 5.0, 7.0, 9.0]);
+        assert!(rank_deficient.is_singular(1e-10));
+
+        // Tiny entries have a vanishing determinant but are not singular
+        let scaled = Matrix::new(2, 2, vec![1e-200, 0.0, 0.0, 1e-200]);
+        assert_eq!(scaled.determinant(), 0.0);
+        assert!(!scaled.is_singular(0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Singularity check is only defined for square matrices")]
+    fn test_is_singular_non_square() {
+        let matrix = Matrix::new(1, 2, vec![1.0, 2.0]);
+        let _singular = matrix.is_singular(1e-10); // Should panic
+    }
+
+    /// Tests the 1-norm condition number estimate.
+    #[test]
+    fn test_condition_estimate() {
+        let well_conditioned = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 3.0]);
+        // ||A||_1 = 4 and A^-1 = [[0.6, -0.2], [-0.2, 0.4]] has ||A^-1||_1 = 0.8
+        assert!((well_conditioned.condition_estimate() - 3.2).abs() < 1e-10);
+
+        let near_singular = Matrix::new(2, 2, vec![1.0, 1.0, 1.0, 1.0 + 1e-10]);
+        assert!(near_singular.condition_estimate() > 1e9);
+
+        let singular = Matrix::new(3, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 2.0, 4.0, 6.0]);
+        assert_eq!(singular.condition_estimate(), f64::INFINITY);
+    }
+
+    /// Tests finding the dominant eigenpair by power iteration.
+    #[test]
+    fn test_dominant_eigenpair() {
+        // Eigenvalues are 3 + √3, 3 and 3 - √3
+        let matrix = Matrix::new(3, 3, vec![4.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 2.0]);
+        let (eigenvalue, v) = matrix.dominant_eigenpair(1000, 1e-10).unwrap();
+        assert!((eigenvalue - (3.0 + 3.0f64.sqrt())).abs() < 1e-9);
+        assert!((v.norm() - 1.0).abs() < 1e-12);
+
+        // The Rayleigh quotient of the eigenvector reproduces the eigenvalue
+        let av = Vector::new((0..3).map(|i| matrix.row(i).dot(&v)).collect());
+        assert!((v.dot(&av) / v.dot(&v) - eigenvalue).abs() < 1e-9);
+        assert!((av - v.clone() * eigenvalue).norm() < 1e-9);
+
+        // A negative dominant eigenvalue is found despite the sign flipping
+        let negative = Matrix::new(2, 2, vec![-3.0, 0.0, 0.0, 1.0]);
+        let (eigenvalue, _) = negative.dominant_eigenpair(1000, 1e-10).unwrap();
+        assert!((eigenvalue + 3.0).abs() < 1e-9);
+
+        // Eigenvalues ±1 of equal magnitude prevent convergence
+        let reflection = Matrix::new(2, 2, vec![1.0, 0.0, 0.0, -1.0]);
+        assert!(reflection.dominant_eigenpair(100, 1e-10).is_none());
+
+        // The all-ones vector is an eigenvector of the smaller eigenvalue -1,
+        // so the dominant eigenvalue 3 must not be skipped
+        let matrix = Matrix::new(2, 2, vec![1.0, -2.0, -2.0, 1.0]);
+        let (eigenvalue, v) = matrix.dominant_eigenpair(1000, 1e-10).unwrap();
+        assert!((eigenvalue - 3.0).abs() < 1e-9);
+        assert!((v.components[0] + v.components[1]).abs() < 1e-9);
+    }
+
+    /// Tests matrix inversion of real and complex matrices.
+    #[test]
+    fn test_inverse() {
+        let matrix = Matrix::new(3, 3, vec![
+            2.0, -3.0, 1.0,
+            2.0, 0.0, -1.0,
+            1.0, 4.0, 5.0
+        ]);
+        let product = &matrix * &matrix.inverse().unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product.get(i, j) - expected).abs() < 1e-10);
+            }
+        }
+
+        let complex = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, 0.0),
+            Complex::new(0.0, 1.0), Complex::new(2.0, -1.0)
+        ]);
+        let product = &complex * &complex.inverse().unwrap();
+        let identity = Matrix::identity(2);
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((*product.get(i, j) - *identity.get(i, j)).magnitude() < 1e-10);
+            }
+        }
+
+        let singular = Matrix::new(3, 3, vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0
+        ]);
+        assert!(singular.inverse().is_none());
+    }
+
+    /// Tests solving a linear system.
+    #[test]
+    fn test_solve() {
+        // x + 2y + 3z = 14, 2x - y + z = 3, 3x + y - z = 2  =>  (1, 2, 3)
+        let a = Matrix::new(3, 3, vec![
+            1.0, 2.0, 3.0,
+            2.0, -1.0, 1.0,
+            3.0, 1.0, -1.0
+        ]);
+        let b = Vector::new(vec![14.0, 3.0, 2.0]);
+
+        let x = a.solve(&b).unwrap();
+        assert!((x.components[0] - 1.0).abs() < 1e-10);
+        assert!((x.components[1] - 2.0).abs() < 1e-10);
+        assert!((x.components[2] - 3.0).abs() < 1e-10);
+
+        // Residual |Ax - b| is tiny
+        let ax: Vec<f64> = (0..3)
+            .map(|i| (0..3).map(|j| a.get(i, j) * x.components[j]).sum())
+            .collect();
+        assert!((Vector::new(ax) - b).norm() < 1e-10);
+
+        let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert!(singular.solve(&Vector::new(vec![1.0, 2.0])).is_none());
+    }
+
+    /// Tests that the LU factors reconstruct the row-permuted matrix.
+    #[test]
+    fn test_lu_decomposition() {
+        let a = Matrix::new(3, 3, vec![
+            1.0, 2.0, 3.0,
+            2.0, -1.0, 1.0,
+            3.0, 1.0, -1.0
+        ]);
+        let (l, u, permutation) = a.lu().unwrap();
+
+        let mut pa = Matrix::zeros(3, 3);
+        for (i, &row) in permutation.iter().enumerate() {
+            for j in 0..3 {
+                pa.set(i, j, *a.get(row, j));
+            }
+        }
+
+        let product = &l * &u;
+        for i in 0..3 {
+            assert_eq!(*l.get(i, i), 1.0);
+            for j in 0..3 {
+                if j > i {
+                    assert_eq!(*l.get(i, j), 0.0);
+                }
+                if j < i {
+                    assert_eq!(*u.get(i, j), 0.0);
+                }
+                assert!((product.get(i, j) - pa.get(i, j)).abs() < 1e-10);
+            }
+        }
+
+        let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert!(singular.lu().is_none());
+    }
+
+    /// Tests the trace of real and complex matrices.
+    #[test]
+    fn test_trace() {
+        let real = Matrix::new(3, 3, vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0
+        ]);
+        assert_eq!(real.trace(), 15.0);
+
+        let complex = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, 4.0),
+            Complex::new(5.0, 6.0), Complex::new(7.0, -8.0)
+        ]);
+        assert_eq!(complex.trace(), Complex::new(8.0, -6.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Trace is only defined for square matrices")]
+    fn test_trace_non_square() {
+        let matrix = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let _trace = matrix.trace(); // Should panic
+    }
+
+    /// Tests building matrices from rows and columns.
+    #[test]
+    fn test_from_rows_and_cols() {
+        let data = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+        let by_rows = Matrix::from_rows(data.clone());
+        let by_cols = Matrix::from_cols(data);
+
+        assert_eq!(by_rows, Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]));
+        assert_eq!(by_cols, by_rows.transpose());
+    }
+
+    /// Tests that `to_rows` is the inverse of `from_rows`.
+    #[test]
+    fn test_to_rows_round_trip() {
+        let matrix = Matrix::new(3, 2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let rows = matrix.to_rows();
+        assert_eq!(rows, vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+        assert_eq!(Matrix::from_rows(rows), matrix);
+
+        let complex = Matrix::new(1, 2, vec![Complex::new(1.0, -1.0), Complex::new(0.0, 2.0)]);
+        assert!(Matrix::from_rows(complex.to_rows()) == complex);
+    }
+
+    #[test]
+    #[should_panic(expected = "All rows must have the same length")]
+    fn test_from_rows_ragged() {
+        let _matrix = Matrix::from_rows(vec![vec![1.0, 2.0], vec![3.0]]); // Should panic
+    }
+
+    #[test]
+    #[should_panic(expected = "All columns must have the same length")]
+    fn test_from_cols_ragged() {
+        let _matrix = Matrix::from_cols(vec![vec![1.0], vec![2.0, 3.0]]); // Should panic
+    }
+
+    /// Tests extracting rows and columns as vectors.
+    #[test]
+    fn test_row_and_col() {
+        let real = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(real.row(0), Vector::new(vec![1.0, 2.0, 3.0]));
+        assert_eq!(real.col(2), Vector::new(vec![3.0, 6.0]));
+
+        let complex = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, 4.0),
+            Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)
+        ]);
+        assert!(complex.row(0) == ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]));
+        assert!(complex.col(0) == ComplexVector::new(vec![Complex::new(1.0, 2.0), Complex::new(5.0, 6.0)]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Column index 3 out of bounds for matrix with 3 columns")]
+    fn test_col_out_of_bounds() {
+        let matrix = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let _col = matrix.col(3); // Should panic
+    }
+
+    /// Tests the aligned Display output.
+    #[test]
+    fn test_matrix_display() {
+        let matrix = Matrix::new(2, 3, vec![
+            1.0, -2.5, 100.0,
+            12.0, 3.0, 4.0
+        ]);
+        assert_eq!(format!("{}", matrix), "[ 1 -2.5 100]\n[12    3   4]");
+        assert_eq!(format!("{:.2}", matrix), "[ 1.00 -2.50 100.00]\n[12.00  3.00   4.00]");
+    }
+
+    /// Tests reading and writing elements through indexing.
+    #[test]
+    fn test_matrix_indexing() {
+        let mut matrix = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(matrix[(0, 2)], 3.0);
+        assert_eq!(matrix[(1, 0)], 4.0);
+
+        matrix[(1, 2)] = 10.0;
+        matrix[(0, 0)] += 1.0;
+        assert_eq!(matrix.get(1, 2), &10.0);
+        assert_eq!(matrix.get(0, 0), &2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index (0, 3) out of bounds for 2x3 matrix")]
+    fn test_matrix_index_out_of_bounds() {
+        let matrix = Matrix::new(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let _element = matrix[(0, 3)]; // Should panic
+    }
+
+    /// Tests multiplying matrices by scalars.
+    #[test]
+    fn test_scalar_multiplication() {
+        let real = Matrix::new(2, 2, vec![1.0, -2.0, 3.5, 4.0]);
+        assert_eq!(real.clone() * 3.0, real.clone() + real.clone() + real.clone());
+
+        let complex = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, 4.0),
+            Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)
+        ]);
+        assert_eq!(complex.clone() * 2.0, complex.clone() + complex.clone());
+
+        let rotated = complex.clone() * Complex::I;
+        assert_eq!(*rotated.get(0, 0), Complex::new(-2.0, 1.0));
+        assert_eq!(*rotated.get(1, 1), Complex::new(-8.0, 7.0));
+    }
+
+    /// Tests tolerance-based matrix equality.
+    #[test]
+    fn test_approx_eq() {
+        let a = Matrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+        let b = Matrix::new(2, 2, vec![1.0, 2.0 + 1e-12, 3.0, 4.0 - 1e-12]);
+        assert!(a != b);
+        assert!(a.approx_eq(&b, 1e-10));
+        assert!(!a.approx_eq(&b, 1e-14));
+        assert!(!a.approx_eq(&Matrix::new(1, 4, vec![1.0, 2.0, 3.0, 4.0]), 1.0));
+
+        let c = Matrix::new(1, 2, vec![Complex::new(1.0, -1.0), Complex::new(0.5, 2.0)]);
+        let d = Matrix::new(1, 2, vec![Complex::new(1.0, -1.0 + 1e-12), Complex::new(0.5 - 1e-12, 2.0)]);
+        assert!(c != d);
+        assert!(c.approx_eq(&d, 1e-10));
+        assert!(!c.approx_eq(&d, 1e-14));
+        assert!(!c.approx_eq(&c.transpose(), 1.0));
+    }
+
+    /// Tests Hermitian and symmetric matrix checks.
+    #[test]
+    fn test_hermitian_and_symmetric() {
+        let hermitian = Matrix::new(3, 3, vec![
+            Complex::new(1.0, 0.0), Complex::new(2.0, 3.0), Complex::new(0.0, -1.0),
+            Complex::new(2.0, -3.0), Complex::new(5.0, 0.0), Complex::new(4.0, 0.0),
+            Complex::new(0.0, 1.0), Complex::new(4.0, 0.0), Complex::new(-2.0, 0.0)
+        ]);
+        assert!(hermitian.is_hermitian(1e-10));
+
+        // Symmetric but not Hermitian because of complex off-diagonal entries
+        let complex_symmetric = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 0.0), Complex::new(0.0, 1.0),
+            Complex::new(0.0, 1.0), Complex::new(1.0, 0.0)
+        ]);
+        assert!(!complex_symmetric.is_hermitian(1e-10));
+
+        // Non-real diagonal
+        let non_real_diagonal = Matrix::new(1, 1, vec![Complex::new(1.0, 1.0)]);
+        assert!(!non_real_diagonal.is_hermitian(1e-10));
+
+        let symmetric = Matrix::new(3, 3, vec![
+            1.0, 7.0, 3.0,
+            7.0, 4.0, -5.0,
+            3.0, -5.0, 6.0
+        ]);
+        assert!(symmetric.is_symmetric(1e-10));
+        assert!(!Matrix::new(2, 2, vec![1.0, 2.0, 2.1, 1.0]).is_symmetric(1e-10));
+
+        // Non-square matrices are never symmetric or Hermitian
+        assert!(!Matrix::new(1, 2, vec![1.0, 1.0]).is_symmetric(1e-10));
+        assert!(!Matrix::new(2, 1, vec![Complex::new(1.0, 0.0); 2]).is_hermitian(1e-10));
+    }
+
+    /// Tests the Kronecker product of matrices.
+    #[test]
+    fn test_kronecker_product() {
+        let identity = Matrix::<Complex>::identity(2);
+        assert_eq!(identity.kronecker(&identity), Matrix::identity(4));
+
+        let s = 1.0 / 2.0f64.sqrt();
+        let hadamard = Matrix::new(2, 2, vec![
+            Complex::new(s, 0.0), Complex::new(s, 0.0),
+            Complex::new(s, 0.0), Complex::new(-s, 0.0)
+        ]);
+        let phase = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(0.0, 1.0)
+        ]);
+        let product = hadamard.kronecker(&phase);
+        assert_eq!(product.rows(), 4);
+        assert_eq!(product.cols(), 4);
+        assert_eq!(*product.get(3, 3), Complex::new(0.0, -s));
+        assert!(product.is_unitary());
+    }
+
+    /// Tests raising a matrix to integer powers.
+    #[test]
+    fn test_matrix_power() {
+        let m = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 1.0), Complex::new(2.0, 0.0),
+            Complex::new(0.0, -1.0), Complex::new(3.0, 0.5)
+        ]);
+        assert_eq!(m.pow(0), Matrix::identity(2));
+        assert_eq!(m.pow(1), m);
+        assert_eq!(m.pow(3), &(&m * &m) * &m);
+
+        let expected = (0..6).fold(Matrix::identity(2), |acc, _| &acc * &m);
+        let power = m.pow(6);
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((*power.get(i, j) - *expected.get(i, j)).magnitude() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix power is only defined for square matrices")]
+    fn test_matrix_power_non_square() {
+        let matrix = Matrix::new(1, 2, vec![Complex::new(1.0, 0.0); 2]);
+        let _power = matrix.pow(2); // Should panic
+    }
+
+    /// Tests extracting blocks and minors.
+    #[test]
+    fn test_submatrix_and_minor() {
+        let matrix = Matrix::new(3, 3, vec![
+            1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0,
+            7.0, 8.0, 9.0
+        ]);
+        assert_eq!(matrix.submatrix(0..2, 1..3), Matrix::new(2, 2, vec![2.0, 3.0, 5.0, 6.0]));
+        assert_eq!(matrix.submatrix(1..3, 1..3), Matrix::new(2, 2, vec![5.0, 6.0, 8.0, 9.0]));
+        assert_eq!(matrix.submatrix(0..3, 0..3), matrix);
+        assert_eq!(matrix.minor(0, 2), Matrix::new(2, 2, vec![4.0, 5.0, 7.0, 8.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Row range 2..4 out of bounds for matrix with 3 rows")]
+    fn test_submatrix_out_of_bounds() {
+        let matrix = Matrix::new(3, 3, vec![1.0; 9]);
+        let _block = matrix.submatrix(2..4, 0..2); // Should panic
+    }
+
+    /// Tests horizontal and vertical concatenation.
+    #[test]
+    fn test_hstack_and_vstack() {
+        let matrix = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, 4.0),
+            Complex::new(5.0, 6.0), Complex::new(7.0, 8.0)
+        ]);
+        let identity = Matrix::identity(2);
+
+        let augmented = matrix.hstack(&identity);
+        assert_eq!(augmented.rows(), 2);
+        assert_eq!(augmented.cols(), 4);
+        assert_eq!(augmented.submatrix(0..2, 0..2), matrix);
+        assert_eq!(augmented.submatrix(0..2, 2..4), identity);
+
+        let stacked = matrix.vstack(&identity);
+        assert_eq!(stacked.rows(), 4);
+        assert_eq!(stacked.cols(), 2);
+        assert_eq!(stacked.submatrix(0..2, 0..2), matrix);
+        assert_eq!(stacked.submatrix(2..4, 0..2), identity);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrices must have same number of rows")]
+    fn test_hstack_mismatch() {
+        let a = Matrix::new(2, 1, vec![1.0, 2.0]);
+        let b = Matrix::new(1, 1, vec![3.0]);
+        let _stacked = a.hstack(&b); // Should panic
+    }
+
+    /// Tests the Frobenius norm and distance.
+    #[test]
+    fn test_frobenius_norm() {
+        let real = Matrix::new(2, 2, vec![1.0, -2.0, 3.0, 4.0]);
+        assert!((real.frobenius_norm() - 30.0f64.sqrt()).abs() < 1e-10);
+        assert!((real.frobenius_distance(&Matrix::zeros(2, 2)) - real.frobenius_norm()).abs() < 1e-10);
+
+        // |1+2i|^2 + |3-4i|^2 + |i|^2 + |2|^2 = 5 + 25 + 1 + 4 = 35
+        let complex = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, -4.0),
+            Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)
+        ]);
+        assert!((complex.frobenius_norm() - 35.0f64.sqrt()).abs() < 1e-10);
+
+        let shifted = complex.clone() + Matrix::identity(2);
+        assert!((complex.frobenius_distance(&shifted) - 2.0f64.sqrt()).abs() < 1e-10);
+    }
+
+    /// Tests closed-form eigenvalues of a 2x2 matrix.
+    #[test]
+    fn test_eigenvalues_2x2() {
+        // Characteristic polynomial λ² - 2λ + 5 has roots 1 ± 2i
+        let matrix = Matrix::new(2, 2, vec![
+            Complex::new(1.0, 0.0), Complex::new(-2.0, 0.0),
+            Complex::new(2.0, 0.0), Complex::new(1.0, 0.0)
+        ]);
+        let (l1, l2) = matrix.eigenvalues_2x2();
+        assert!((l1 - Complex::new(1.0, 2.0)).magnitude() < 1e-10);
+        assert!((l2 - Complex::new(1.0, -2.0)).magnitude() < 1e-10);
+
+        // Eigenvalues sum to the trace and multiply to the determinant
+        assert!((l1 + l2 - matrix.trace()).magnitude() < 1e-10);
+        assert!((l1 * l2 - matrix.determinant()).magnitude() < 1e-10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Closed-form eigenvalues require a 2x2 matrix")]
+    fn test_eigenvalues_2x2_wrong_size() {
+        let _eigenvalues = Matrix::<Complex>::identity(3).eigenvalues_2x2(); // Should panic
+    }
+
+    /// Tests the shifted QR eigenvalue solver.
+    #[test]
+    fn test_eigenvalues() {
+        // Eigenvalues 2 - √2, 2, 2 + √2
+        let symmetric = Matrix::new(3, 3, vec![
+            2.0, -1.0, 0.0,
+            -1.0, 2.0, -1.0,
+            0.0, -1.0, 2.0
+        ]);
+        let eigenvalues = symmetric.eigenvalues(1000, 1e-12);
+        let mut real_parts: Vec<f64> = eigenvalues.iter().map(|l| l.real).collect();
+        real_parts.sort_by(|a, b| a.total_cmp(b));
+        let expected = [2.0 - 2.0f64.sqrt(), 2.0, 2.0 + 2.0f64.sqrt()];
+        for (actual, expected) in real_parts.iter().zip(expected.iter()) {
+            assert!((actual - expected).abs() < 1e-6);
+        }
+        assert!(eigenvalues.iter().all(|l| l.imag.abs() < 1e-6));
+        let sum = eigenvalues.iter().fold(Complex::new(0.0, 0.0), |acc, l| acc + *l);
+        assert!((sum.real - symmetric.trace()).abs() < 1e-6);
+
+        // Diagonal matrices return their diagonal
+        let diagonal = Matrix::new(3, 3, vec![
+            Complex::new(5.0, 0.0), Complex::new(0.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(-1.0, 0.0), Complex::new(0.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(2.0, 0.0)
+        ]);
+        assert_eq!(diagonal.eigenvalues(100, 1e-12), vec![
+            Complex::new(5.0, 0.0), Complex::new(-1.0, 0.0), Complex::new(2.0, 0.0)
+        ]);
+    }
+
+    /// Tests the eigenvalue solver on a Hermitian matrix.
+    #[test]
+    fn test_hermitian_eigenvalues() {
+        // Eigenvalues of [[2, 1-i], [1+i, 3]] are 1 and 4
+        let hermitian = Matrix::new(2, 2, vec![
+            Complex::new(2.0, 0.0), Complex::new(1.0, -1.0),
+            Complex::new(1.0, 1.0), Complex::new(3.0, 0.0)
+        ]);
+        let mut eigenvalues: Vec<f64> = hermitian.eigenvalues(100, 1e-12).iter().map(|l| l.real).collect();
+        eigenvalues.sort_by(|a, b| a.total_cmp(b));
+        assert!((eigenvalues[0] - 1.0).abs() < 1e-6);
+        assert!((eigenvalues[1] - 4.0).abs() < 1e-6);
+    }
+
+    /// Tests QR decomposition of real and complex matrices.
+    #[test]
+    fn test_qr_decomposition() {
+        let real = Matrix::new(4, 3, vec![
+            12.0, -51.0, 4.0,
+            6.0, 167.0, -68.0,
+            -4.0, 24.0, -41.0,
+            1.0, 2.0, 3.0
+        ]);
+        let (q, r) = real.qr();
+        assert_eq!(q.rows(), 4);
+        assert_eq!(r.rows(), 4);
+        assert_eq!(r.cols(), 3);
+
+        let product = &q * &r;
+        let orthogonality = &q.transpose() * &q;
+        for i in 0..4 {
+            for j in 0..3 {
+                assert!((product.get(i, j) - real.get(i, j)).abs() < 1e-10);
+                if i > j {
+                    assert_eq!(*r.get(i, j), 0.0);
+                }
+            }
+            for j in 0..4 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((orthogonality.get(i, j) - expected).abs() < 1e-10);
+            }
+        }
+
+        let complex = Matrix::new(3, 3, vec![
+            Complex::new(1.0, 2.0), Complex::new(3.0, 0.0), Complex::new(0.0, -1.0),
+            Complex::new(2.0, -1.0), Complex::new(0.0, 1.0), Complex::new(4.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(1.0, 1.0), Complex::new(2.0, 2.0)
+        ]);
+        let (q, r) = complex.qr();
+        assert!(q.is_unitary());
+        assert!((&q * &r).frobenius_distance(&complex) < 1e-10);
+        for i in 0..3 {
+            for j in 0..i {
+                assert_eq!(*r.get(i, j), Complex::new(0.0, 0.0));
+            }
+        }
+    }
+
+    /// Tests Cholesky decomposition of symmetric positive-definite matrices.
+    #[test]
+    fn test_cholesky() {
+        let spd = Matrix::new(3, 3, vec![
+            4.0, 12.0, -16.0,
+            12.0, 37.0, -43.0,
+            -16.0, -43.0, 98.0
+        ]);
+        let l = spd.cholesky().unwrap();
+        assert_eq!(l, Matrix::new(3, 3, vec![
+            2.0, 0.0, 0.0,
+            6.0, 1.0, 0.0,
+            -8.0, 5.0, 3.0
+        ]));
+        assert!((&l * &l.transpose()).frobenius_distance(&spd) < 1e-10);
+
+        // Symmetric but indefinite
+        let indefinite = Matrix::new(2, 2, vec![1.0, 3.0, 3.0, 1.0]);
+        assert!(indefinite.cholesky().is_none());
+
+        // Not symmetric
+        let asymmetric = Matrix::new(2, 2, vec![4.0, 1.0, 2.0, 4.0]);
+        assert!(asymmetric.cholesky().is_none());
+    }
+
+    /// Tests the elementary row operations.
+    #[test]
+    fn test_row_operations() {
+        let mut matrix = Matrix::new(3, 2, vec![
+            1.0, 2.0,
+            3.0, 4.0,
+            5.0, 6.0
+        ]);
+
+        matrix.swap_rows(0, 2);
+        assert_eq!(matrix, Matrix::new(3, 2, vec![5.0, 6.0, 3.0, 4.0, 1.0, 2.0]));
+
+        matrix.scale_row(1, 2.0);
+        assert_eq!(matrix, Matrix::new(3, 2, vec![5.0, 6.0, 6.0, 8.0, 1.0, 2.0]));
+
+        matrix.add_scaled_row(0, 2, -5.0);
+        assert_eq!(matrix, Matrix::new(3, 2, vec![0.0, -4.0, 6.0, 8.0, 1.0, 2.0]));
+
+        let mut complex = Matrix::<Complex>::identity(2);
+        complex.add_scaled_row(0, 1, Complex::I);
+        assert_eq!(*complex.get(0, 1), Complex::I);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row index 3 out of bounds for matrix with 3 rows")]
+    fn test_row_operation_out_of_bounds() {
+        let mut matrix = Matrix::new(3, 1, vec![1.0, 2.0, 3.0]);
+        matrix.swap_rows(0, 3); // Should panic
+    }
+
+    /// Tests element-wise function application.
+    #[test]
+    fn test_map() {
+        let matrix = Matrix::new(2, 2, vec![1.0, -2.0, 3.0, 0.5]);
+        assert_eq!(matrix.map(|x| x * 2.0), Matrix::new(2, 2, vec![2.0, -4.0, 6.0, 1.0]));
+
+        let complex = matrix.map(|&x| Complex::new(x, -x));
+        assert_eq!(complex.rows(), 2);
+        assert_eq!(*complex.get(1, 0), Complex::new(3.0, -3.0));
+
+        let conjugated = complex.map(|c| c.conjugate());
+        assert_eq!(*conjugated.get(1, 0), Complex::new(3.0, 3.0));
+    }
+
+    /// Embeds `a` as the leading block of a `size x size` matrix padded with the identity.
+    fn embed_in_identity(a: &Matrix<Complex>, size: usize) -> Matrix<Complex> {
+        let mut padded = Matrix::identity(size);
+        for i in 0..a.rows() {
+            for j in 0..a.cols() {
+                padded.set(i, j, *a.get(i, j));
+            }
+        }
+        padded
+    }
+
+    /// Tests the closed-form 2x2 and 3x3 determinant, and small inverses, against padded matrices.
+    #[test]
+    fn test_closed_form_determinant_and_inverse() {
+        for n in [2, 3] {
+            for seed in 0..20 {
+                // Padding with the identity keeps the determinant and the leading
+                // block of the inverse, but forces the general LU determinant
+                let a = pseudo_random_complex_matrix(n, n, seed);
+                let padded = embed_in_identity(&a, 5);
+                assert!((a.determinant() - padded.determinant()).magnitude() < 1e-12);
+                let inverse = a.inverse().unwrap();
+                let general = padded.inverse().unwrap().submatrix(0..n, 0..n);
+                assert!(inverse.approx_eq(&general, 1e-9));
+
+                let real = a.map(|z| z.real);
+                let real_padded = padded.map(|z| z.real);
+                assert!((real.determinant() - real_padded.determinant()).abs() < 1e-12);
+                let inverse = real.inverse().unwrap();
+                let general = real_padded.inverse().unwrap().submatrix(0..n, 0..n);
+                assert!(inverse.approx_eq(&general, 1e-9));
+            }
+        }
+
+        let singular = Matrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]);
+        assert_eq!(singular.determinant(), 0.0);
+        assert!(singular.inverse().is_none());
+    }
+
+    /// Tests the matrix exponential on diagonal, nilpotent and skew-Hermitian matrices.
+    #[test]
+    fn test_matrix_exp() {
+        // Diagonal matrices exponentiate elementwise
+        let diag = [Complex::new(1.0, 0.0), Complex::new(-2.0, 0.5), Complex::new(0.0, 3.0), Complex::new(4.0, -1.0)];
+        let expected = Matrix::from_diagonal(&diag.map(|z| z.exp()));
+        assert!(Matrix::from_diagonal(&diag).exp().approx_eq(&expected, 1e-8));
+
+        // Nilpotent matrix: e^N = I + N
+        let nilpotent = Matrix::new(2, 2, vec![
+            Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+            Complex::new(0.0, 0.0), Complex::new(0.0, 0.0)
+        ]);
+        let expected = &Matrix::identity(2) + &nilpotent;
+        assert!(nilpotent.exp().approx_eq(&expected, 1e-12));
+
+        // The exponential of the zero matrix is the identity
+        let zero: Matrix<Complex> = Matrix::zeros(3, 3);
+        assert!(zero.exp().approx_eq(&Matrix::identity(3), 1e-15));
+
+        // Skew-Hermitian matrices exponentiate to unitary matrices
+        for seed in 0..5 {
+            let a = pseudo_random_complex_matrix(4, 4, seed) * 3.0;
+            let skew = &a - &a.conjugate_transpose();
+            let u = skew.exp();
+            let product = &u * &u.conjugate_transpose();
+            assert!(product.approx_eq(&Matrix::identity(4), 1e-8));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix exponential is only defined for square matrices")]
+    fn test_matrix_exp_non_square() {
+        let matrix: Matrix<Complex> = Matrix::zeros(2, 3);
+        let _exp = matrix.exp(); // Should panic
+    }
+
+    /// Tests that the exponential of a matrix with a non-finite norm is NaN rather than hanging.
+    #[test]
+    fn test_matrix_exp_non_finite() {
+        for value in [f64::INFINITY, f64::NAN] {
+            let matrix = Matrix::new(2, 2, vec![
+                Complex::new(value, 0.0), Complex::new(0.0, 0.0),
+                Complex::new(0.0, 0.0), Complex::new(1.0, 0.0),
+            ]);
+            let result = matrix.exp();
+            assert_eq!((result.rows(), result.cols()), (2, 2));
+            for i in 0..2 {
+                for j in 0..2 {
+                    let z = result.get(i, j);
+                    assert!(z.real.is_nan() && z.imag.is_nan());
+                }
+            }
+        }
+    }
+
+    /// Tests least-squares line fitting and rank-deficiency detection.
+    #[test]
+    fn test_least_squares() {
+        // Points near y = 2x + 1 with small perturbations
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let ys = [1.1, 2.9, 5.2, 6.8, 9.1, 10.9];
+        let mut data = Vec::new();
+        for &x in &xs {
+            data.extend([1.0, x]);
+        }
+        let a = Matrix::new(xs.len(), 2, data);
+        let b = Vector::new(ys.to_vec());
+
+        let fit = a.least_squares(&b).unwrap();
+        assert!((fit.components[0] - 1.0).abs() < 0.2);
+        assert!((fit.components[1] - 2.0).abs() < 0.1);
+
+        // The residual is orthogonal to the columns of A
+        let sum_of_squares = |x: &Vector| {
+            (0..xs.len())
+                .map(|i| (a.get(i, 0) * x.components[0] + a.get(i, 1) * x.components[1] - ys[i]).powi(2))
+                .sum::<f64>()
+        };
+        let residual: Vec<f64> = (0..xs.len())
+            .map(|i| a.get(i, 0) * fit.components[0] + a.get(i, 1) * fit.components[1] - ys[i])
+            .collect();
+        for j in 0..2 {
+            let projection: f64 = (0..xs.len()).map(|i| a.get(i, j) * residual[i]).sum();
+            assert!(projection.abs() < 1e-10);
+        }
+
+        // Any perturbation of the fit increases the residual
+        let best = sum_of_squares(&fit);
+        for (d0, d1) in [(1e-3, 0.0), (-1e-3, 0.0), (0.0, 1e-3), (0.0, -1e-3), (1e-3, -1e-3)] {
+            let perturbed = Vector::new(vec![fit.components[0] + d0, fit.components[1] + d1]);
+            assert!(sum_of_squares(&perturbed) > best);
+        }
+
+        // Square systems agree with solve
+        let square = Matrix::new(2, 2, vec![2.0, 1.0, 1.0, 3.0]);
+        let rhs = Vector::new(vec![5.0, 10.0]);
+        let x = square.least_squares(&rhs).unwrap();
+        let expected = square.solve(&rhs).unwrap();
+        assert!((x - expected).norm() < 1e-10);
+
+        // Linearly dependent columns are rank deficient
+        let deficient = Matrix::new(3, 2, vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0]);
+        assert!(deficient.least_squares(&Vector::new(vec![1.0, 2.0, 3.0])).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Least squares requires at least as many rows as columns")]
+    fn test_least_squares_wide_matrix() {
+        let matrix = Matrix::new(1, 2, vec![1.0, 2.0]);
+        let _x = matrix.least_squares(&Vector::new(vec![1.0])); // Should panic
+    }
+
+    /// Tests density matrix validation and trace normalization.
+    #[test]
+    fn test_density_matrix() {
+        // Pure state |ψ><ψ| with ψ = (|0> + i|1>)/√2
+        let psi = ComplexVector::new(vec![Complex::new(1.0, 0.0), Complex::new(0.0, 1.0)]).normalize();
+        let pure = psi.outer_product(&psi);
+        assert!(pure.is_density_matrix(1e-10));
+
+        // Mixed state with a coherence term
+        let mixed = Matrix::new(2, 2, vec![
+            Complex::new(0.75, 0.0), Complex::new(0.1, -0.2),
+            Complex::new(0.1, 0.2), Complex::new(0.25, 0.0)
+        ]);
+        assert!(mixed.is_density_matrix(1e-10));
+
+        // Trace different from one
+        let unnormalized = mixed.clone() * 2.0;
+        assert!(!unnormalized.is_density_matrix(1e-10));
+        let normalized = unnormalized.normalize_trace();
+        assert!(normalized.approx_eq(&mixed, 1e-12));
+        assert!(normalized.is_density_matrix(1e-10));
+
+        // Hermitian with unit trace but a negative eigenvalue
+        let indefinite = Matrix::from_diagonal(&[Complex::new(1.5, 0.0), Complex::new(-0.5, 0.0)]);
+        assert!(!indefinite.is_density_matrix(1e-10));
+
+        // Not Hermitian
+        let skewed = Matrix::new(2, 2, vec![
+            Complex::new(0.5, 0.0), Complex::new(0.0, 1.0),
+            Complex::new(0.0, 1.0), Complex::new(0.5, 0.0)
+        ]);
+        assert!(!skewed.is_density_matrix(1e-10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot normalize a matrix with zero trace")]
+    fn test_normalize_trace_zero() {
+        let _normalized = Matrix::<Complex>::pauli_z().normalize_trace(); // Should panic
+    }
+
+    /// Tests the complex determinant against `ad - bc` and a case needing pivoting.
+    #[test]
+    fn test_complex_determinant() {
+        let (a, b) = (Complex::new(2.0, -1.0), Complex::new(0.5, 3.0));
+        let (c, d) = (Complex::new(-1.0, 4.0), Complex::new(1.5, 0.25));
+        let matrix = Matrix::new(2, 2, vec![a, b, c, d]);
+        assert!((matrix.determinant() - (a * d - b * c)).magnitude() < 1e-12);
+
+        // Zero leading pivots force row exchanges in the elimination path:
+        // det of block-diag([[0, a], [b, 0]], [[0, c], [d, 0]]) = (-ab)(-cd)
+        let zero = Complex::new(0.0, 0.0);
+        let blocks = Matrix::new(4, 4, vec![
+            zero, a, zero, zero,
+            b, zero, zero, zero,
+            zero, zero, zero, c,
+            zero, zero, d, zero,
+        ]);
+        assert!((blocks.determinant() - a * b * c * d).magnitude() < 1e-12);
+
+        // Swapping two rows negates the determinant
+        let mut swapped = blocks.clone();
+        swapped.swap_rows(0, 2);
+        assert!((swapped.determinant() + a * b * c * d).magnitude() < 1e-12);
+    }
+
+    /// Tests the additive and multiplicative identities used by generic constructors.
+    #[test]
+    fn test_zero_and_one_traits() {
+        assert_eq!(<f64 as Zero>::zero(), 0.0);
+        assert_eq!(<f64 as One>::one(), 1.0);
+        assert_eq!(<Complex as Zero>::zero(), Complex::ZERO);
+        assert_eq!(<Complex as One>::one(), Complex::ONE);
+        assert_eq!(Complex::ZERO, Complex::new(0.0, 0.0));
+        assert_eq!(Complex::ONE, Complex::new(1.0, 0.0));
+
+        let zeros: Matrix<Complex> = Matrix::zeros(2, 3);
+        assert!(zeros.to_rows().iter().flatten().all(|&z| z == Complex::ZERO));
+        let identity: Matrix<f64> = Matrix::identity(3);
+        assert_eq!(identity.trace(), 3.0);
+        assert_eq!(Matrix::<Complex>::identity(2).trace(), Complex::new(2.0, 0.0));
+    }
+
+    /// Tests scaling rows and columns to unit norm, skipping zero ones.
+    #[test]
+    fn test_normalize_rows_and_columns() {
+        let mut matrix = pseudo_random_complex_matrix(4, 3, 7) * 10.0;
+        for j in 0..3 {
+            matrix.set(2, j, Complex::new(0.0, 0.0));
+        }
+
+        let rows = matrix.normalize_rows();
+        for i in 0..4 {
+            if i == 2 {
+                assert_eq!(rows.row(i), matrix.row(i));
+            } else {
+                assert!((rows.row(i).norm() - 1.0).abs() < 1e-12);
+                // Each row keeps its direction
+                let ratio = *rows.get(i, 0) / *matrix.get(i, 0);
+                assert!(ratio.imag.abs() < 1e-12 && ratio.real > 0.0);
+            }
+        }
+
+        let columns = matrix.normalize_columns();
+        for j in 0..3 {
+            assert!((columns.col(j).norm() - 1.0).abs() < 1e-12);
+        }
+
+        let zero_column = Matrix::new(2, 2, vec![
+            Complex::new(0.0, 0.0), Complex::new(1.0, 1.0),
+            Complex::new(0.0, 0.0), Complex::new(1.0, -1.0)
+        ]);
+        let normalized = zero_column.normalize_columns();
+        assert_eq!(normalized.col(0), zero_column.col(0));
+        assert!((normalized.col(1).norm() - 1.0).abs() < 1e-12);
+    }
+
+    /// Tests that small and large inverses use the same singularity criterion as `solve`.
+    #[test]
+    fn test_inverse_singularity_matches_solve() {
+        for n in [2, 3, 4] {
+            for small in [1e-7, 1e-13] {
+                let mut diagonal = vec![small; n];
+                diagonal[0] = 1.0;
+                let mut matrix = Matrix::zeros(n, n);
+                for (i, &d) in diagonal.iter().enumerate() {
+                    matrix.set(i, i, d);
+                }
+
+                let b = Vector::new(vec![1.0; n]);
+                assert_eq!(matrix.inverse().is_some(), matrix.solve(&b).is_some());
+                assert_eq!(matrix.inverse().is_some(), !matrix.is_singular(1e-12));
+            }
+        }
+
+        // diag(1, 1e-7, 1e-7) is well within the pivot tolerance
+        let matrix = Matrix::new(3, 3, vec![1.0, 0.0, 0.0, 0.0, 1e-7, 0.0, 0.0, 0.0, 1e-7]);
+        let inverse = matrix.inverse().unwrap();
+        assert!((inverse.get(1, 1) - 1e7).abs() < 1e-3);
+    }
+
+    /// Tests owned and borrowed addition and subtraction for a non-Copy element type.
+    #[test]
+    fn test_add_sub_non_copy_elements() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug, PartialEq)]
+        struct Boxed(Box<i64>);
+
+        impl Clone for Boxed {
+            fn clone(&self) -> Boxed {
+                CLONES.fetch_add(1, Ordering::SeqCst);
+                Boxed(self.0.clone())
+            }
+        }
+
+        impl std::ops::Add for Boxed {
+            type Output = Boxed;
+            fn add(self, other: Boxed) -> Boxed {
+                Boxed(Box::new(*self.0 + *other.0))
+            }
+        }
+
+        impl std::ops::Sub for Boxed {
+            type Output = Boxed;
+            fn sub(self, other: Boxed) -> Boxed {
+                Boxed(Box::new(*self.0 - *other.0))
+            }
+        }
+
+        let boxed = |values: [i64; 2]| Matrix::new(1, 2, Vec::from(values.map(|x| Boxed(Box::new(x)))));
+
+        // The owned forms move elements and never clone
+        assert_eq!(boxed([1, 2]) + boxed([10, 20]), boxed([11, 22]));
+        assert_eq!(boxed([10, 20]) - boxed([1, 2]), boxed([9, 18]));
+        assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+
+        // The borrowed forms clone both operands and give the same results
+        let a = boxed([1, 2]);
+        let b = boxed([10, 20]);
+        assert_eq!(&a + &b, boxed([11, 22]));
+        assert_eq!(&b - &a, boxed([9, 18]));
+        assert_eq!(CLONES.load(Ordering::SeqCst), 8);
+    }
+
+    /// Tests zeros, identity, trace and negation for other primitive element types.
+    #[test]
+    fn test_primitive_element_types() {
+        let zeros = Matrix::<f32>::zeros(2, 2);
+        assert_eq!(zeros, Matrix::new(2, 2, vec![0.0f32; 4]));
+
+        let identity = Matrix::<i64>::identity(3);
+        assert_eq!(identity.trace(), 3);
+        assert_eq!(-identity, Matrix::new(3, 3, vec![-1i64, 0, 0, 0, -1, 0, 0, 0, -1]));
+
+        let identity = Matrix::<u8>::identity(2);
+        assert_eq!(identity.trace(), 2);
+    }
+
+    /// Tests zeros and identity for single-precision complex elements.
+    #[test]
+    fn test_complex32_elements() {
+        let zeros = Matrix::<Complex32>::zeros(2, 3);
+        assert_eq!(zeros, Matrix::new(2, 3, vec![Complex32::ZERO; 6]));
+
+        let identity = Matrix::<Complex32>::identity(2);
+        assert_eq!(identity.trace(), Complex32::new(2.0, 0.0));
+        assert_eq!(-identity, Matrix::new(2, 2, vec![-Complex32::ONE, Complex32::ZERO, Complex32::ZERO, -Complex32::ONE]));
+    }
+}
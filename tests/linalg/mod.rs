@@ -1 +1,2 @@
-mod matrix_tests; 
\ No newline at end of file
+mod matrix_tests;
+mod vector_tests;
\ No newline at end of file
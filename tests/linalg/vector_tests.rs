@@ -0,0 +1,68 @@
+use rusticle::complex::Complex;
+use rusticle::linalg::Vector;
+
+/// Test suite for the real Vector type.
+/// 
+/// These tests verify the core functionality of the Vector type, including:
+/// - Basic vector operations (addition, subtraction, scalar multiplication)
+/// - Dot product and norm calculations
+/// - Vector normalization and conversion to complex vectors
+mod vector_tests {
+    use super::*;
+
+    /// Tests basic vector operations.
+    #[test]
+    fn test_vector_operations() {
+        let v1 = Vector::new(vec![1.0, 2.0, 3.0]);
+        let v2 = Vector::new(vec![4.0, 5.0, 6.0]);
+
+        assert_eq!((v1.clone() + v2.clone()).components, vec![5.0, 7.0, 9.0]);
+        assert_eq!((v1.clone() - v2.clone()).components, vec![-3.0, -3.0, -3.0]);
+        assert_eq!((v1.clone() * 2.0).components, vec![2.0, 4.0, 6.0]);
+        assert_eq!((2.0 * v1.clone()).components, vec![2.0, 4.0, 6.0]);
+        assert_eq!((-v1).components, vec![-1.0, -2.0, -3.0]);
+    }
+
+    /// Tests dot product and norm calculations.
+    #[test]
+    fn test_dot_product_and_norm() {
+        let v1 = Vector::new(vec![1.0, 2.0, 3.0]);
+        let v2 = Vector::new(vec![4.0, -5.0, 6.0]);
+        assert_eq!(v1.dot(&v2), 12.0);
+
+        let v = Vector::new(vec![2.0, 3.0, 6.0]);
+        assert_eq!(v.norm(), 7.0);
+        assert_eq!(v.norm_squared(), 49.0);
+    }
+
+    /// Tests vector normalization.
+    #[test]
+    fn test_normalization() {
+        let v = Vector::new(vec![2.0, 3.0, 6.0]);
+        let normalized = v.normalize();
+        assert!((normalized.norm() - 1.0).abs() < 1e-10);
+        assert!((normalized.components[2] - 6.0 / 7.0).abs() < 1e-10);
+    }
+
+    /// Tests conversion to a complex vector.
+    #[test]
+    fn test_to_complex() {
+        let v = Vector::new(vec![1.5, -2.0]);
+        let complex = v.to_complex();
+        assert_eq!(complex.dimension(), 2);
+        assert_eq!(complex.components[0], Complex::new(1.5, 0.0));
+        assert_eq!(complex.components[1], Complex::new(-2.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must have the same dimension for dot product")]
+    fn test_dot_dimension_mismatch() {
+        let _dot = Vector::zeros(2).dot(&Vector::zeros(3)); // Should panic
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot normalize a zero vector")]
+    fn test_normalize_zero_vector() {
+        let _normalized = Vector::zeros(2).normalize(); // Should panic
+    }
+}
@@ -1,2 +1,4 @@
+#![allow(clippy::module_inception)]
+
 mod complex;
-mod linalg; 
\ No newline at end of file
+mod linalg;
\ No newline at end of file